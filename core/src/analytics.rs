@@ -0,0 +1,202 @@
+//! Cross-commit analytics built on top of `Repository`'s Git CLI wrapper: contribution and
+//! ownership statistics scoped to individual monorepo subprojects.
+
+use crate::models::{AuthorStats, CodeOwnership, ContributionStats};
+use crate::repository::{execute_git_fn, Repository};
+use crate::types::Result;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The `--format` string used by the per-project analytics scan: a record separator followed
+/// by the commit hash and author, with `git log --numstat` appending per-file change lines
+/// after each record.
+///
+/// `%x1e` lets each commit's record (header line plus its numstat lines) be split
+/// unambiguously with `str::split('\x1e')`, matching the convention used by `COMMIT_LOG_FORMAT`.
+const PROJECT_LOG_FORMAT: &str = "%x1e%H%x1f%an%x1f%at";
+
+/// Same as `PROJECT_LOG_FORMAT`, but using the mailmap-aware `%aN` placeholder.
+const PROJECT_LOG_FORMAT_MAILMAP: &str = "%x1e%H%x1f%aN%x1f%at";
+
+/// Defines a monorepo subproject for per-project analytics.
+///
+/// A file belongs to a project if its path starts with any of `paths`, which are root
+/// directories relative to the repository root (e.g. `"crates/core"`).
+#[derive(Debug, Clone)]
+pub struct ProjectDefinition {
+    /// Unique identifier for this project, used as the map key in analytics results.
+    pub id: String,
+    /// Path roots (relative to the repository root) that belong to this project.
+    pub paths: Vec<String>,
+}
+
+impl ProjectDefinition {
+    fn owns(&self, path: &Path) -> bool {
+        self.paths.iter().any(|root| path.starts_with(root))
+    }
+}
+
+/// Normalizes a `--numstat` path column to the file's current path, undoing the rename
+/// notation Git renders it with.
+///
+/// A renamed file is rendered either as `old/path => new/path` (when old and new share no
+/// common directory) or as `common/prefix/{old => new}/suffix` (when they do). Neither form
+/// is a real path, so `ProjectDefinition::owns`'s prefix check would silently miss them;
+/// collapsing to the new path here keeps renamed files attributed to their project.
+fn numstat_path_to_current(raw: &str) -> PathBuf {
+    if let Some(open) = raw.find('{') {
+        if let Some(close_rel) = raw[open..].find('}') {
+            let close = open + close_rel;
+            if let Some(arrow) = raw[open..close].find(" => ") {
+                let new_part = &raw[open + arrow + 4..close];
+                return PathBuf::from(format!("{}{}{}", &raw[..open], new_part, &raw[close + 1..]));
+            }
+        }
+    }
+
+    if let Some(arrow) = raw.find(" => ") {
+        return PathBuf::from(&raw[arrow + 4..]);
+    }
+
+    PathBuf::from(raw)
+}
+
+/// Per-project contribution and ownership analytics, as returned by
+/// `Repository::analyze_contributions_by_project`.
+#[derive(Debug, Clone)]
+pub struct ProjectStats {
+    /// Contribution statistics for commits touching this project.
+    pub contributions: ContributionStats,
+    /// Code ownership for files belonging to this project.
+    pub ownership: CodeOwnership,
+}
+
+impl Repository {
+    /// Attributes each commit's author and line changes to every monorepo subproject whose
+    /// path roots it touches, producing independent `ContributionStats`/`CodeOwnership` per
+    /// project instead of one aggregate across the whole repository.
+    ///
+    /// A commit that spans multiple subprojects contributes to each of them.
+    ///
+    /// # Arguments
+    /// * `projects` - The subprojects to attribute commits to, defined by path roots.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn analyze_contributions_by_project(
+        &self,
+        projects: &[ProjectDefinition],
+    ) -> Result<HashMap<String, ProjectStats>> {
+        let mut args = vec!["log", "--numstat"];
+        if self.use_mailmap {
+            args.push("--use-mailmap");
+        }
+
+        let format = if self.use_mailmap { PROJECT_LOG_FORMAT_MAILMAP } else { PROJECT_LOG_FORMAT };
+        let format_arg = format!("--format={}", format);
+        args.push(&format_arg);
+
+        // Execute command, keeping the raw output so `\x1e`/`\x1f` separators survive intact.
+        let output = execute_git_fn(&self.location, &args, |output| Ok(output.to_string()))?;
+
+        let mut result: HashMap<String, ProjectStats> = HashMap::new();
+
+        for record in output.split('\x1e').filter(|r| !r.trim().is_empty()) {
+            let mut lines = record.lines();
+            let header = lines.next().unwrap_or("");
+            let mut fields = header.splitn(3, '\x1f');
+            let _hash = fields.next().unwrap_or("");
+            let author = fields.next().unwrap_or("").to_string();
+            let timestamp: u64 = fields.next().unwrap_or("0").trim().parse().unwrap_or(0);
+
+            let mut touched_files: Vec<(PathBuf, usize, usize)> = Vec::new();
+            for line in lines {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                let mut cols = line.splitn(3, '\t');
+                let added = cols.next().unwrap_or("0");
+                let removed = cols.next().unwrap_or("0");
+                let path = match cols.next() {
+                    Some(path) => path,
+                    None => continue,
+                };
+
+                // Binary files report "-" for both counts.
+                let added: usize = added.parse().unwrap_or(0);
+                let removed: usize = removed.parse().unwrap_or(0);
+                touched_files.push((numstat_path_to_current(path), added, removed));
+            }
+
+            for project in projects {
+                let touched: Vec<&(PathBuf, usize, usize)> = touched_files
+                    .iter()
+                    .filter(|(path, _, _)| project.owns(path))
+                    .collect();
+
+                if touched.is_empty() {
+                    continue;
+                }
+
+                let stats = result.entry(project.id.clone()).or_insert_with(|| ProjectStats {
+                    contributions: ContributionStats {
+                        by_author: HashMap::new(),
+                        total_commits: 0,
+                        total_authors: 0,
+                        total_added: 0,
+                        total_removed: 0,
+                        total_files_changed: 0,
+                    },
+                    ownership: CodeOwnership {
+                        files: HashMap::new(),
+                        directories: HashMap::new(),
+                    },
+                });
+
+                let commit_added: usize = touched.iter().map(|(_, added, _)| added).sum();
+                let commit_removed: usize = touched.iter().map(|(_, _, removed)| removed).sum();
+
+                stats.contributions.total_commits += 1;
+                stats.contributions.total_added += commit_added;
+                stats.contributions.total_removed += commit_removed;
+                stats.contributions.total_files_changed += touched.len();
+
+                let author_stats = stats.contributions.by_author.entry(author.clone()).or_insert_with(|| AuthorStats {
+                    commits: 0,
+                    added_lines: 0,
+                    removed_lines: 0,
+                    files_changed: 0,
+                    first_commit: timestamp,
+                    last_commit: timestamp,
+                });
+                author_stats.commits += 1;
+                author_stats.added_lines += commit_added;
+                author_stats.removed_lines += commit_removed;
+                author_stats.files_changed += touched.len();
+                author_stats.first_commit = author_stats.first_commit.min(timestamp);
+                author_stats.last_commit = author_stats.last_commit.max(timestamp);
+
+                for (path, added, removed) in &touched {
+                    let weight = added + removed;
+
+                    let file_owners = stats.ownership.files.entry(path.clone()).or_insert_with(HashMap::new);
+                    *file_owners.entry(author.clone()).or_insert(0) += weight;
+
+                    if let Some(dir) = path.parent() {
+                        let dir_owners = stats.ownership.directories.entry(dir.to_path_buf()).or_insert_with(HashMap::new);
+                        *dir_owners.entry(author.clone()).or_insert(0) += weight;
+                    }
+                }
+            }
+        }
+
+        for stats in result.values_mut() {
+            stats.contributions.total_authors = stats.contributions.by_author.len();
+        }
+
+        Ok(result)
+    }
+}
@@ -2,20 +2,69 @@
 
 use crate::error::GitError;
 use crate::models::*;
-use crate::repository::CloneOptions;
+use crate::repository::{parse_branch_list_output, CloneOptions, DiffOptions, BRANCH_LIST_FORMAT, EMPTY_TREE_OID};
 use crate::types::{BranchName, CommitHash, GitUrl, Remote, Result};
 
 use std::ffi::OsStr;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::str::{self, FromStr};
+use std::time::Duration;
 
+use futures::stream::{self, Stream, StreamExt};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
 
+/// Matches a `git --progress` stage line such as `"Receiving objects:  42% (210/500)"` or
+/// `"Writing objects: 100% (5/5), 1.23 MiB | 2.00 MiB/s, done."`.
+static PROGRESS_STAGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:remote: )?(Receiving objects|Writing objects|Indexing objects): *(\d+)% \((\d+)/(\d+)\)(?:, ([\d.]+) (KiB|MiB|GiB))?")
+        .expect("Invalid static progress stage regex")
+});
+
+/// Matches the summary line `git` prints once transfer negotiation completes, e.g.
+/// `"Total 500 (delta 20), reused 10 (delta 0), pack-reused 450"`.
+static PROGRESS_TOTAL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^Total (\d+) \(delta \d+\), reused (\d+)")
+        .expect("Invalid static progress total regex")
+});
+
+/// Configuration applied to every Git invocation made through an `AsyncRepository`, so
+/// non-interactive callers can avoid hanging on credential prompts and can supply whatever
+/// SSH/HTTP credentials or commit identity the operation needs.
+#[derive(Debug, Clone, Default)]
+pub struct ExecConfig {
+    /// If `true`, leaves Git free to prompt on the terminal for credentials. Defaults to
+    /// `false`, which sets `GIT_TERMINAL_PROMPT=0` so a non-interactive caller fails fast
+    /// instead of hanging.
+    pub allow_terminal_prompt: bool,
+    /// Value for `GIT_SSH_COMMAND`, overriding the SSH command Git uses for `ssh://`/scp-style
+    /// remotes (e.g. to point at a specific private key).
+    pub ssh_command: Option<String>,
+    /// Value for `GIT_ASKPASS`, a program Git runs to answer a credential prompt.
+    pub askpass: Option<String>,
+    /// A header value applied via `-c http.extraHeader=<value>`, for token-based HTTP(S)
+    /// authentication (e.g. `"Authorization: Bearer <token>"`).
+    pub http_extra_header: Option<String>,
+    /// Author identity injected as `GIT_AUTHOR_NAME`/`GIT_AUTHOR_EMAIL` for commands that
+    /// create commits.
+    pub author: Option<(String, String)>,
+    /// Committer identity injected as `GIT_COMMITTER_NAME`/`GIT_COMMITTER_EMAIL` for commands
+    /// that create commits.
+    pub committer: Option<(String, String)>,
+    /// Maximum time to let a single Git invocation run before it is killed and
+    /// `GitError::TimedOut` is returned.
+    pub timeout: Option<Duration>,
+}
+
 /// Represents a local Git repository with async operations.
 #[derive(Debug, Clone)]
 pub struct AsyncRepository {
     location: PathBuf,
+    config: ExecConfig,
 }
 
 impl AsyncRepository {
@@ -32,6 +81,7 @@ impl AsyncRepository {
     pub async fn open<P: AsRef<Path>>(p: P) -> Result<AsyncRepository> {
         let repo = AsyncRepository {
             location: PathBuf::from(p.as_ref()),
+            config: ExecConfig::default(),
         };
 
         // Verify this is actually a git repository
@@ -53,6 +103,25 @@ impl AsyncRepository {
         Ok(repo)
     }
 
+    /// Returns this repository with `config` applied to every subsequent Git invocation.
+    ///
+    /// # Arguments
+    /// * `config` - The execution environment and credential settings to use.
+    pub fn with_config(mut self, config: ExecConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Wraps this repository with a TTL cache over its read operations, so repeated lookups
+    /// (e.g. from a UI polling for updates) don't re-fork `git` for data that hasn't changed.
+    ///
+    /// # Arguments
+    /// * `config` - The cache's TTLs and capacity.
+    #[cfg(feature = "cache")]
+    pub fn with_cache(self, config: crate::cache::CacheConfig) -> crate::cache::CachedAsyncRepository {
+        crate::cache::CachedAsyncRepository::new(self, config)
+    }
+
     /// Clones a remote Git repository into a specified local path asynchronously.
     ///
     /// Equivalent to `git clone <url> <path>` with optional arguments based on CloneOptions.
@@ -103,7 +172,7 @@ impl AsyncRepository {
         args.push(url.as_ref());
         args.push(p_ref.as_os_str());
 
-        execute_git_async(cwd, args).await?;
+        execute_git_async(cwd, &ExecConfig::default(), args).await?;
 
         Self::open(p_ref).await
     }
@@ -120,6 +189,7 @@ impl AsyncRepository {
     pub async fn list_branches(&self) -> Result<Vec<BranchName>> {
         execute_git_fn_async(
             &self.location,
+            &self.config,
             &["branch", "--list", "--format=%(refname:short)"],
             |output| {
                 output
@@ -140,11 +210,21 @@ impl AsyncRepository {
     /// # Errors
     /// Returns `GitError` (including `GitNotFound`).
     pub async fn list_tracked(&self) -> Result<Vec<String>> {
-        execute_git_fn_async(&self.location, &["ls-files"], |output| {
+        execute_git_fn_async(&self.location, &self.config, &["ls-files"], |output| {
             Ok(output.lines().map(|line| line.to_owned()).collect())
         }).await
     }
 
+    /// Streams the files tracked by Git in the working directory, one path per item, without
+    /// buffering the whole `git ls-files` output in memory.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`) if the process fails to spawn.
+    pub async fn list_tracked_stream(&self) -> Result<impl Stream<Item = Result<String>>> {
+        let lines = execute_git_stream_async(&self.location, &self.config, ["ls-files"]).await?;
+        Ok(lines)
+    }
+
     /// Gets the URL configured for a specific remote asynchronously.
     ///
     /// Equivalent to `git config --get remote.<remote_name>.url`.
@@ -160,6 +240,7 @@ impl AsyncRepository {
     pub async fn show_remote_uri(&self, remote_name: &Remote) -> Result<GitUrl> {
         execute_git_fn_async(
             &self.location,
+            &self.config,
             &[
                 "config",
                 "--get",
@@ -180,7 +261,7 @@ impl AsyncRepository {
     /// Returns `GitError::NoRemoteRepositorySet` if no remotes are configured.
     /// Returns `GitError` (including `GitNotFound`).
     pub async fn list_remotes(&self) -> Result<Vec<Remote>> {
-        execute_git_fn_async(&self.location, &["remote"], |output| {
+        execute_git_fn_async(&self.location, &self.config, &["remote"], |output| {
             let remote_names: Vec<&str> = output.lines().map(|line| line.trim()).collect();
             if remote_names.is_empty() {
                 let config_check = self.cmd_out(["config", "--get-regexp", r"^remote\..*\.url"]).await;
@@ -218,6 +299,7 @@ impl AsyncRepository {
         };
         execute_git_fn_async(
             &self.location,
+            &self.config,
             args,
             |output| CommitHash::from_str(output.trim()),
         ).await
@@ -238,7 +320,7 @@ impl AsyncRepository {
         I: IntoIterator<Item = S>,
         S: AsRef<OsStr>,
     {
-        execute_git_fn_async(&self.location, args, |output| {
+        execute_git_fn_async(&self.location, &self.config, args, |output| {
             Ok(output.lines().map(|line| line.to_owned()).collect())
         }).await
     }
@@ -254,22 +336,15 @@ impl AsyncRepository {
     /// # Errors
     /// Returns `GitError` (including `GitNotFound`).
     pub async fn get_commit(&self, commit_ref: Option<&str>) -> Result<Commit> {
-        let format = "%H%n\
-                     shortcommit %h%n\
-                     author_name %an%n\
-                     author_email %ae%n\
-                     timestamp %at%n\
-                     %P%n\
-                     message %s";
-
-        let format_string = format!("--format={}", format);
+        let format_string = format!("--format={}", COMMIT_LOG_FORMAT);
         let args = match commit_ref {
             Some(c) => vec!["show", "--no-patch", &format_string, c],
             None => vec!["show", "--no-patch", &format_string],
         };
 
-        execute_git_fn_async(&self.location, args, |output| {
-            Commit::from_show_format(output).ok_or_else(|| GitError::GitError {
+        execute_git_fn_async(&self.location, &self.config, args, |output| {
+            // The format begins with a leading record separator; strip it before parsing.
+            Commit::from_record(output.trim_start_matches('\x1e').trim_end()).ok_or_else(|| GitError::GitError {
                 stdout: output.to_string(),
                 stderr: "Failed to parse commit information".to_string(),
             })
@@ -286,6 +361,7 @@ impl AsyncRepository {
     pub async fn status(&self) -> Result<StatusResult> {
         let porcelain_output = execute_git_fn_async(
             &self.location,
+            &self.config,
             &["status", "--porcelain=v2", "--branch"],
             |output| Ok(output.to_string())
         ).await?;
@@ -380,42 +456,31 @@ impl AsyncRepository {
     pub async fn list_branches_info(&self) -> Result<Vec<Branch>> {
         execute_git_fn_async(
             &self.location,
-            &["branch", "--list", "-v", "--format=%(refname:short) %(objectname) %(HEAD) %(upstream:short)"],
-            |output| {
-                let mut branches = Vec::new();
-
-                for line in output.lines() {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 3 {
-                        let name_str = parts[0];
-                        let commit_str = parts[1];
-                        let is_head = parts[2] == "*";
-
-                        let upstream = if parts.len() >= 4 {
-                            Some(parts[3].to_string())
-                        } else {
-                            None
-                        };
+            &self.config,
+            &["branch", "--list", "-v", &format!("--format={}", BRANCH_LIST_FORMAT)],
+            parse_branch_list_output,
+        )
+        .await
+    }
 
-                        if let Ok(name) = BranchName::from_str(name_str) {
-                            if let Ok(commit_hash) = CommitHash::from_str(commit_str) {
-                                branches.push(Branch {
-                                    name,
-                                    commit: commit_hash,
-                                    is_head,
-                                    upstream,
-                                });
-                            } else {
-                                eprintln!("Warning: Could not parse commit hash '{}' for branch '{}'", commit_str, name_str);
-                            }
-                        } else {
-                            eprintln!("Warning: Could not parse branch name '{}'", name_str);
-                        }
-                    }
-                }
-                Ok(branches)
-            }
-        ).await
+    /// Lists branches with detailed information asynchronously, sorted by descending
+    /// last-commit recency.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn list_branches_by_recency(&self) -> Result<Vec<Branch>> {
+        execute_git_fn_async(
+            &self.location,
+            &self.config,
+            &[
+                "for-each-ref",
+                "--sort=-committerdate",
+                &format!("--format={}", BRANCH_LIST_FORMAT),
+                "refs/heads/",
+            ],
+            parse_branch_list_output,
+        )
+        .await
     }
 
     /// Gets a list of commits in the repository history asynchronously.
@@ -436,85 +501,352 @@ impl AsyncRepository {
         skip: Option<usize>,
         branch: Option<&BranchName>,
     ) -> Result<Vec<Commit>> {
-        let mut args = vec!["log"];
-
-        // Format string for parsing commit info
-        let format_arg = format!(
-            "--format=%H%n{}%n{}%n{}%n{}%n{}%n%P%n{}",
-            "shortcommit %h",
-            "author_name %an",
-            "author_email %ae",
-            "timestamp %at",
-            "message %s",
-        );
+        let mut commits = Vec::new();
+        let stream = self.commit_history_stream(limit, skip, branch).await?;
+        tokio::pin!(stream);
+
+        while let Some(commit) = stream.next().await {
+            commits.push(commit?);
+        }
+
+        Ok(commits)
+    }
 
-        args.push(&format_arg);
+    /// Streams commits in the repository history, one `Commit` per item, without buffering the
+    /// whole `git log` output in memory. Lets callers process a large history incrementally and
+    /// stop early without waiting for the rest.
+    ///
+    /// # Arguments
+    /// * `limit` - Optional maximum number of commits to return.
+    /// * `skip` - Optional number of commits to skip from the beginning.
+    /// * `branch` - Optional branch name to get history for. If None, uses current branch.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`) if the process fails to spawn.
+    pub async fn commit_history_stream(
+        &self,
+        limit: Option<usize>,
+        skip: Option<usize>,
+        branch: Option<&BranchName>,
+    ) -> Result<impl Stream<Item = Result<Commit>>> {
+        let mut args = vec!["log".to_string()];
+        args.push(format!("--format={}", COMMIT_LOG_FORMAT));
 
-        // Apply limit and skip
         if let Some(limit_val) = limit {
-            args.push("--max-count");
-            args.push(&limit_val.to_string());
+            args.push("--max-count".to_string());
+            args.push(limit_val.to_string());
         }
 
         if let Some(skip_val) = skip {
-            args.push("--skip");
-            args.push(&skip_val.to_string());
+            args.push("--skip".to_string());
+            args.push(skip_val.to_string());
+        }
+
+        if let Some(b) = branch {
+            args.push(b.to_string());
+        }
+
+        let records = execute_git_record_stream_async(&self.location, &self.config, args).await?;
+
+        // Each record is delimited by a `\x1e` record separator rather than a fixed line count,
+        // so a commit body (`%b`) spanning multiple lines can't misalign the grouping.
+        Ok(records.filter_map(|record| async move {
+            match record {
+                Ok(text) => {
+                    let trimmed = text.trim_end_matches('\n');
+                    if trimmed.is_empty() {
+                        None
+                    } else {
+                        Commit::from_record(trimmed).map(Ok)
+                    }
+                }
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
+
+    /// Creates a new local branch asynchronously, optionally from a given start point.
+    ///
+    /// Equivalent to `git branch <name> [start_point]`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn create_branch(&self, name: &BranchName, start_point: Option<&str>) -> Result<Branch> {
+        let mut args = vec!["branch", name.as_ref()];
+        if let Some(start) = start_point {
+            args.push(start);
         }
 
-        // If branch is specified, add it to the command
+        execute_git_async(&self.location, &self.config, args).await?;
+        self.find_branch(name).await
+    }
+
+    /// Deletes a local branch asynchronously.
+    ///
+    /// Equivalent to `git branch -d <name>`, or `-D` to force-delete an unmerged branch.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn delete_branch(&self, name: &BranchName, force: bool) -> Result<()> {
+        let flag = if force { "-D" } else { "-d" };
+        execute_git_async(&self.location, &self.config, &["branch", flag, name.as_ref()]).await
+    }
+
+    /// Renames a local branch asynchronously.
+    ///
+    /// Equivalent to `git branch -m <old> <new>`, or `-M` to overwrite an existing branch.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn rename_branch(&self, old: &BranchName, new: &BranchName, force: bool) -> Result<Branch> {
+        let flag = if force { "-M" } else { "-m" };
+        execute_git_async(&self.location, &self.config, &["branch", flag, old.as_ref(), new.as_ref()]).await?;
+        self.find_branch(new).await
+    }
+
+    /// Checks out a branch, tag, or commit, optionally creating a new branch at that point.
+    ///
+    /// Equivalent to `git checkout [-b] <name>`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn checkout(&self, name: &str, create: bool) -> Result<StatusResult> {
+        let mut args = vec!["checkout"];
+        if create {
+            args.push("-b");
+        }
+        args.push(name);
+
+        execute_git_async(&self.location, &self.config, args).await?;
+        self.status().await
+    }
+
+    /// Merges a branch or commit into the current branch asynchronously.
+    ///
+    /// Equivalent to `git merge [--no-ff] <name>`. A conflicting merge is not treated as a
+    /// failure: the resulting `StatusResult` (with `merging` set) is returned instead, so
+    /// callers can inspect the conflict through the same status flags `status()` exposes.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`) for failures unrelated to the merge
+    /// itself (e.g. the repository being inaccessible).
+    pub async fn merge_branch(&self, name: &str, no_ff: bool) -> Result<StatusResult> {
+        let mut args = vec!["merge"];
+        if no_ff {
+            args.push("--no-ff");
+        }
+        args.push(name);
+
+        match execute_git_async(&self.location, &self.config, args).await {
+            Ok(()) | Err(GitError::GitError { .. }) => self.status().await,
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Rebases the current branch onto `upstream` asynchronously.
+    ///
+    /// Equivalent to `git rebase <upstream>`. A rebase that stops on a conflict is not treated
+    /// as a failure: the resulting `StatusResult` (with `rebasing` set) is returned instead.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`) for failures unrelated to the rebase
+    /// itself (e.g. the repository being inaccessible).
+    pub async fn rebase_onto(&self, upstream: &str) -> Result<StatusResult> {
+        match execute_git_async(&self.location, &self.config, &["rebase", upstream]).await {
+            Ok(()) | Err(GitError::GitError { .. }) => self.status().await,
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Looks up a single branch by name via `list_branches_info`.
+    async fn find_branch(&self, name: &BranchName) -> Result<Branch> {
+        self.list_branches_info()
+            .await?
+            .into_iter()
+            .find(|branch| branch.name.to_string() == name.to_string())
+            .ok_or_else(|| GitError::InvalidRefName(name.to_string()))
+    }
+
+    /// Fetches from a remote asynchronously, reporting transfer statistics.
+    ///
+    /// Equivalent to `git fetch --progress <remote> [refspecs...]`.
+    ///
+    /// # Arguments
+    /// * `remote` - The remote to fetch from.
+    /// * `refspecs` - Specific refspecs to fetch, or empty to use the remote's configured ones.
+    /// * `on_progress` - Invoked with each parsed progress line as it streams in.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn fetch<F>(&self, remote: &Remote, refspecs: &[&str], on_progress: F) -> Result<TransferStats>
+    where
+        F: FnMut(Progress),
+    {
+        let mut args = vec!["fetch", "--progress", remote.as_ref()];
+        args.extend(refspecs.iter().copied());
+
+        execute_git_with_progress_async(&self.location, &self.config, args, on_progress).await
+    }
+
+    /// Pulls from a remote asynchronously, reporting transfer statistics.
+    ///
+    /// Equivalent to `git pull --progress <remote> [branch]`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn pull<F>(&self, remote: &Remote, branch: Option<&BranchName>, on_progress: F) -> Result<TransferStats>
+    where
+        F: FnMut(Progress),
+    {
+        let mut args = vec!["pull", "--progress", remote.as_ref()];
         if let Some(b) = branch {
             args.push(b.as_ref());
         }
 
-        // Execute command
-        let output = self.cmd_out(&args).await?;
+        execute_git_with_progress_async(&self.location, &self.config, args, on_progress).await
+    }
 
-        // Parse commits
-        let mut commits = Vec::new();
-        let mut current_lines = Vec::new();
+    /// Pushes to a remote asynchronously, reporting transfer statistics.
+    ///
+    /// Equivalent to `git push --progress [--force] <remote> [branch]`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn push<F>(&self, remote: &Remote, branch: Option<&BranchName>, force: bool, on_progress: F) -> Result<TransferStats>
+    where
+        F: FnMut(Progress),
+    {
+        let mut args = vec!["push", "--progress"];
+        if force {
+            args.push("--force");
+        }
+        args.push(remote.as_ref());
+        if let Some(b) = branch {
+            args.push(b.as_ref());
+        }
 
-        for line in output {
-            current_lines.push(line);
+        execute_git_with_progress_async(&self.location, &self.config, args, on_progress).await
+    }
 
-            // Each commit has 7 lines in our format
-            if current_lines.len() == 7 {
-                if let Some(commit) = Commit::from_show_format(&current_lines.join("\n")) {
-                    commits.push(commit);
-                }
-                current_lines.clear();
-            }
+    /// Computes the raw unified patch text between two revisions asynchronously.
+    ///
+    /// Equivalent to `git diff --unified=<n> <from> [to]`. `from = None` means
+    /// [`EMPTY_TREE_OID`] (so every tracked file appears as a pure addition); `to = None`
+    /// compares `from` against the current index and working directory.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn diff(&self, from: Option<&str>, to: Option<&str>, opts: DiffOptions) -> Result<String> {
+        let unified = format!("--unified={}", opts.context_lines);
+        let from_rev = from.unwrap_or(EMPTY_TREE_OID);
+
+        let mut args = vec!["diff", "--no-color", &unified, from_rev];
+        if let Some(to_rev) = to {
+            args.push(to_rev);
         }
 
-        Ok(commits)
+        execute_git_fn_async(&self.location, &self.config, args, |output| Ok(output.to_string())).await
+    }
+
+    /// Computes per-file line-count diff statistics between two revisions asynchronously.
+    ///
+    /// Equivalent to `git diff --numstat <from> [to]`. `from = None` means
+    /// [`EMPTY_TREE_OID`]; `to = None` compares `from` against the current index and working
+    /// directory.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn diff_stat(&self, from: Option<&str>, to: Option<&str>) -> Result<Vec<FileDiffStat>> {
+        let from_rev = from.unwrap_or(EMPTY_TREE_OID);
+
+        let mut args = vec!["diff", "--numstat", from_rev];
+        if let Some(to_rev) = to {
+            args.push(to_rev);
+        }
+
+        execute_git_fn_async(&self.location, &self.config, args, |output| Ok(parse_numstat(output))).await
+    }
+
+    /// Summarizes a single commit's footprint, as a sugar for `diff_stat` against the commit's
+    /// parent.
+    ///
+    /// Equivalent to `git show --numstat <hash>`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn diff_commit(&self, hash: &CommitHash) -> Result<Vec<FileDiffStat>> {
+        let hash_str = hash.to_string();
+        execute_git_fn_async(
+            &self.location,
+            &self.config,
+            &["show", "--no-color", "--numstat", "--format=", &hash_str],
+            |output| Ok(parse_numstat(output)),
+        ).await
     }
 }
 
 // --- Async Helper Functions ---
 
+/// Builds a `git` `Command` rooted at `p` with `config`'s environment, credential, and `-c`
+/// settings applied, ready for the caller to append its own arguments.
+///
+/// Always sets `kill_on_drop(true)`, so a command future dropped (e.g. by a `tokio::time::timeout`
+/// elapsing) has its underlying process killed rather than left running.
+fn build_git_command<P: AsRef<Path>>(p: P, config: &ExecConfig) -> Command {
+    let mut command = Command::new("git");
+    command.current_dir(p.as_ref()).kill_on_drop(true);
+
+    if !config.allow_terminal_prompt {
+        command.env("GIT_TERMINAL_PROMPT", "0");
+    }
+    if let Some(ssh_command) = &config.ssh_command {
+        command.env("GIT_SSH_COMMAND", ssh_command);
+    }
+    if let Some(askpass) = &config.askpass {
+        command.env("GIT_ASKPASS", askpass);
+    }
+    if let Some((name, email)) = &config.author {
+        command.env("GIT_AUTHOR_NAME", name).env("GIT_AUTHOR_EMAIL", email);
+    }
+    if let Some((name, email)) = &config.committer {
+        command.env("GIT_COMMITTER_NAME", name).env("GIT_COMMITTER_EMAIL", email);
+    }
+    if let Some(header) = &config.http_extra_header {
+        command.arg("-c").arg(format!("http.extraHeader={}", header));
+    }
+
+    command
+}
+
 /// Executes a Git command asynchronously, discarding successful output.
-async fn execute_git_async<I, S, P>(p: P, args: I) -> Result<()>
+async fn execute_git_async<I, S, P>(p: P, config: &ExecConfig, args: I) -> Result<()>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<OsStr>,
     P: AsRef<Path>,
 {
-    execute_git_fn_async(p, args, |_| Ok(())).await
+    execute_git_fn_async(p, config, args, |_| Ok(())).await
 }
 
 /// Executes a Git command and processes its stdout on success using a closure asynchronously.
-/// Handles errors, including capturing stderr on failure.
-async fn execute_git_fn_async<I, S, P, F, R>(p: P, args: I, process: F) -> Result<R>
+/// Handles errors, including capturing stderr on failure. If `config.timeout` is set and the
+/// command doesn't finish in time, the process is killed and `GitError::TimedOut` is returned.
+async fn execute_git_fn_async<I, S, P, F, R>(p: P, config: &ExecConfig, args: I, process: F) -> Result<R>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<OsStr>,
     P: AsRef<Path>,
     F: FnOnce(&str) -> Result<R>,
 {
-    let command_result = Command::new("git")
-        .current_dir(p.as_ref())
-        .args(args)
-        .output()
-        .await;
+    let output_future = build_git_command(p, config).args(args).output();
+
+    let command_result = match config.timeout {
+        Some(duration) => match tokio::time::timeout(duration, output_future).await {
+            Ok(result) => result,
+            Err(_) => return Err(GitError::TimedOut),
+        },
+        None => output_future.await,
+    };
 
     match command_result {
         Ok(output) => {
@@ -542,4 +874,269 @@ where
             }
         }
     }
+}
+
+/// Executes a Git command asynchronously, streaming its stdout line-by-line instead of
+/// buffering the whole output in memory, so large outputs (e.g. `git log` on a huge history)
+/// can be processed incrementally and abandoned early.
+///
+/// # Errors
+/// Returns `GitError` (including `GitNotFound`) if the process fails to spawn. A non-zero exit
+/// status, or a stdout-decoding failure, surfaces as a single `Err` item at the end of the
+/// stream rather than as a returned `Err` here.
+async fn execute_git_stream_async<I, S, P>(
+    p: P,
+    config: &ExecConfig,
+    args: I,
+) -> Result<impl Stream<Item = Result<String>>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+    P: AsRef<Path>,
+{
+    let mut child = build_git_command(p, config)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                GitError::GitNotFound
+            } else {
+                eprintln!("Failed to spawn async git command: {}", e);
+                GitError::Execution
+            }
+        })?;
+
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let lines = BufReader::new(stdout).lines();
+
+    Ok(stream::unfold(Some((child, lines)), |state| async move {
+        let (mut child, mut lines) = state?;
+
+        match lines.next_line().await {
+            Ok(Some(line)) => Some((Ok(line), Some((child, lines)))),
+            Ok(None) => match child.wait_with_output().await {
+                Ok(output) if output.status.success() => None,
+                Ok(output) => {
+                    let stderr = str::from_utf8(&output.stderr)
+                        .map(|s| s.trim_end().to_owned())
+                        .unwrap_or_else(|_| String::from("[stderr: undecodable UTF-8]"));
+                    Some((Err(GitError::GitError { stdout: String::new(), stderr }), None))
+                }
+                Err(_) => Some((Err(GitError::Execution), None)),
+            },
+            Err(_) => Some((Err(GitError::Undecodable), None)),
+        }
+    }))
+}
+
+/// Like `execute_git_stream_async`, but splits stdout on the `\x1e` record separator instead of
+/// newlines, for `--format` output (such as `COMMIT_LOG_FORMAT`) whose records may embed a
+/// literal newline, e.g. a multi-line commit body via `%b`.
+async fn execute_git_record_stream_async<I, S, P>(
+    p: P,
+    config: &ExecConfig,
+    args: I,
+) -> Result<impl Stream<Item = Result<String>>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+    P: AsRef<Path>,
+{
+    let mut child = build_git_command(p, config)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                GitError::GitNotFound
+            } else {
+                eprintln!("Failed to spawn async git command: {}", e);
+                GitError::Execution
+            }
+        })?;
+
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let reader = BufReader::new(stdout);
+
+    Ok(stream::unfold(Some((child, reader, Vec::new())), |state| async move {
+        let (mut child, mut reader, mut buf) = state?;
+
+        match reader.read_until(b'\x1e', &mut buf).await {
+            Ok(0) => match child.wait_with_output().await {
+                Ok(output) if output.status.success() => None,
+                Ok(output) => {
+                    let stderr = str::from_utf8(&output.stderr)
+                        .map(|s| s.trim_end().to_owned())
+                        .unwrap_or_else(|_| String::from("[stderr: undecodable UTF-8]"));
+                    Some((Err(GitError::GitError { stdout: String::new(), stderr }), None))
+                }
+                Err(_) => Some((Err(GitError::Execution), None)),
+            },
+            Ok(_) => {
+                if buf.last() == Some(&b'\x1e') {
+                    buf.pop();
+                }
+                match String::from_utf8(buf) {
+                    Ok(record) => Some((Ok(record), Some((child, reader, Vec::new())))),
+                    Err(_) => Some((Err(GitError::Undecodable), None)),
+                }
+            }
+            Err(_) => Some((Err(GitError::Undecodable), None)),
+        }
+    }))
+}
+
+/// Executes a Git command that reports transfer progress on stderr (e.g. `fetch`/`pull`/`push`
+/// run with `--progress`), streaming each stderr line to `on_progress` as it arrives instead of
+/// waiting for the process to exit.
+///
+/// Unlike `execute_git_fn_async`, this uses `Command::spawn` with a piped stderr reader so
+/// progress lines are surfaced while the transfer is still in flight.
+async fn execute_git_with_progress_async<I, S, P, F>(
+    p: P,
+    config: &ExecConfig,
+    args: I,
+    on_progress: F,
+) -> Result<TransferStats>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+    P: AsRef<Path>,
+    F: FnMut(Progress),
+{
+    let body = run_with_progress(p, config, args, on_progress);
+
+    match config.timeout {
+        Some(duration) => match tokio::time::timeout(duration, body).await {
+            Ok(result) => result,
+            Err(_) => Err(GitError::TimedOut),
+        },
+        None => body.await,
+    }
+}
+
+/// The body of `execute_git_with_progress_async`, split out so it can be wrapped in
+/// `tokio::time::timeout` without duplicating the spawn/stream/wait logic.
+async fn run_with_progress<I, S, P, F>(p: P, config: &ExecConfig, args: I, mut on_progress: F) -> Result<TransferStats>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+    P: AsRef<Path>,
+    F: FnMut(Progress),
+{
+    let mut child = build_git_command(p, config)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                GitError::GitNotFound
+            } else {
+                eprintln!("Failed to spawn async git command: {}", e);
+                GitError::Execution
+            }
+        })?;
+
+    let stderr = child.stderr.take().expect("stderr was piped");
+    let mut lines = BufReader::new(stderr).lines();
+    let mut stats = TransferStats::default();
+    let mut stderr_text = String::new();
+
+    while let Some(line) = lines.next_line().await.map_err(|_| GitError::Undecodable)? {
+        parse_progress_line(&line, &mut stats);
+        if let Some(percent) = parse_progress_percent(&line) {
+            on_progress(Progress { message: line.clone(), percent: Some(percent) });
+        } else {
+            on_progress(Progress { message: line.clone(), percent: None });
+        }
+
+        stderr_text.push_str(&line);
+        stderr_text.push('\n');
+    }
+
+    let output = child.wait_with_output().await.map_err(|_| GitError::Execution)?;
+
+    if output.status.success() {
+        Ok(stats)
+    } else {
+        let stdout = str::from_utf8(&output.stdout)
+            .map(|s| s.trim_end().to_owned())
+            .unwrap_or_else(|_| String::from("[stdout: undecodable UTF-8]"));
+        Err(GitError::GitError {
+            stdout,
+            stderr: stderr_text.trim_end().to_owned(),
+        })
+    }
+}
+
+/// Updates `stats` from a single line of `git --progress` stderr output, matching the
+/// `Receiving objects`/`Writing objects`/`Indexing objects` stage lines and the final
+/// `Total N (delta M), reused K` summary line.
+fn parse_progress_line(line: &str, stats: &mut TransferStats) {
+    if let Some(caps) = PROGRESS_STAGE_RE.captures(line) {
+        let stage = &caps[1];
+        let current: usize = caps[3].parse().unwrap_or(0);
+        let total: usize = caps[4].parse().unwrap_or(0);
+
+        match stage {
+            "Indexing objects" => {
+                stats.indexed_objects = current;
+                stats.total_objects = stats.total_objects.max(total);
+            }
+            _ => {
+                stats.received_objects = current;
+                stats.total_objects = stats.total_objects.max(total);
+            }
+        }
+
+        if let (Some(value), Some(unit)) = (caps.get(5), caps.get(6)) {
+            stats.received_bytes = parse_byte_size(value.as_str(), unit.as_str());
+        }
+    } else if let Some(caps) = PROGRESS_TOTAL_RE.captures(line) {
+        stats.total_objects = caps[1].parse().unwrap_or(stats.total_objects);
+        stats.local_objects = caps[2].parse().unwrap_or(0);
+    }
+}
+
+/// Converts a `git --progress` size value (e.g. `"1.23"`) and unit (`KiB`/`MiB`/`GiB`) to bytes.
+fn parse_byte_size(value: &str, unit: &str) -> u64 {
+    let value: f64 = value.parse().unwrap_or(0.0);
+    let multiplier = match unit {
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        _ => 1.0,
+    };
+    (value * multiplier) as u64
+}
+
+/// Extracts the percent-complete value from a `git --progress` stage line, if it reports one.
+fn parse_progress_percent(line: &str) -> Option<u8> {
+    PROGRESS_STAGE_RE.captures(line).and_then(|caps| caps[2].parse().ok())
+}
+
+/// Parses the output of `git diff --numstat` (or `git show --numstat`) into `FileDiffStat`s.
+/// A binary file reports `-` for both counts instead of numbers.
+fn parse_numstat(output: &str) -> Vec<FileDiffStat> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let added = fields.next()?;
+            let deleted = fields.next()?;
+            let path = fields.next()?;
+
+            let binary = added == "-" || deleted == "-";
+            Some(FileDiffStat {
+                path: PathBuf::from(path),
+                additions: added.parse().unwrap_or(0),
+                deletions: deleted.parse().unwrap_or(0),
+                binary,
+            })
+        })
+        .collect()
 }
\ No newline at end of file
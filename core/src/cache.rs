@@ -0,0 +1,129 @@
+//! Optional TTL cache layer over `AsyncRepository`'s read operations, so a caller that polls
+//! frequently (e.g. a UI) doesn't re-fork `git` for data that hasn't changed since the last read.
+//!
+//! Gated behind the `cache` feature, since it pulls in `moka` purely for this layer.
+
+use std::time::Duration;
+
+use moka::future::Cache;
+
+use crate::async_repository::AsyncRepository;
+use crate::models::{Branch, Commit, StatusResult};
+use crate::types::{CommitHash, Result};
+
+/// Configuration for `AsyncRepository::with_cache`'s TTL cache layer.
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// Time-to-live for resolved commits, which are immutable once created. Defaults to 5
+    /// minutes.
+    pub commit_ttl: Duration,
+    /// Time-to-live for mutable snapshots (branch listings, `status`), keyed by the repository's
+    /// current `HEAD` oid. Defaults to 10 seconds.
+    pub snapshot_ttl: Duration,
+    /// Maximum number of entries retained in each underlying cache.
+    pub max_capacity: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            commit_ttl: Duration::from_secs(5 * 60),
+            snapshot_ttl: Duration::from_secs(10),
+            max_capacity: 1000,
+        }
+    }
+}
+
+/// Wraps an `AsyncRepository` with a TTL cache over its read operations.
+///
+/// Commit lookups are keyed by `CommitHash` and use `config.commit_ttl`, since a resolved commit
+/// never changes. Branch and status snapshots are keyed by the repository's current `HEAD` oid
+/// and use `config.snapshot_ttl`, so a moving `HEAD` naturally invalidates stale entries.
+#[derive(Clone)]
+pub struct CachedAsyncRepository {
+    repo: AsyncRepository,
+    commits: Cache<CommitHash, Commit>,
+    branches: Cache<CommitHash, Vec<Branch>>,
+    status: Cache<CommitHash, StatusResult>,
+}
+
+impl CachedAsyncRepository {
+    /// Wraps `repo` with a TTL cache configured by `config`.
+    pub(crate) fn new(repo: AsyncRepository, config: CacheConfig) -> Self {
+        CachedAsyncRepository {
+            repo,
+            commits: Cache::builder()
+                .time_to_live(config.commit_ttl)
+                .max_capacity(config.max_capacity)
+                .build(),
+            branches: Cache::builder()
+                .time_to_live(config.snapshot_ttl)
+                .max_capacity(config.max_capacity)
+                .build(),
+            status: Cache::builder()
+                .time_to_live(config.snapshot_ttl)
+                .max_capacity(config.max_capacity)
+                .build(),
+        }
+    }
+
+    /// Gets detailed information about a commit, consulting the cache first.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn get_commit(&self, hash: &CommitHash) -> Result<Commit> {
+        if let Some(commit) = self.commits.get(hash).await {
+            return Ok(commit);
+        }
+
+        let commit = self.repo.get_commit(Some(hash.as_ref())).await?;
+        self.commits.insert(hash.clone(), commit.clone()).await;
+        Ok(commit)
+    }
+
+    /// Lists branches with detailed information, consulting the cache first.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn list_branches_info(&self) -> Result<Vec<Branch>> {
+        let head = self.repo.get_hash(false).await?;
+
+        if let Some(branches) = self.branches.get(&head).await {
+            return Ok(branches);
+        }
+
+        let branches = self.repo.list_branches_info().await?;
+        self.branches.insert(head, branches.clone()).await;
+        Ok(branches)
+    }
+
+    /// Gets the current status of the repository, consulting the cache first.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub async fn status(&self) -> Result<StatusResult> {
+        let head = self.repo.get_hash(false).await?;
+
+        if let Some(status) = self.status.get(&head).await {
+            return Ok(status);
+        }
+
+        let status = self.repo.status().await?;
+        self.status.insert(head, status.clone()).await;
+        Ok(status)
+    }
+
+    /// Drops every cached entry. Call this after a mutating operation (e.g. `create_branch`, `checkout`,
+    /// `merge_branch`) so the next read reflects the new repository state instead of a stale
+    /// snapshot still inside its TTL.
+    pub fn invalidate_all(&self) {
+        self.commits.invalidate_all();
+        self.branches.invalidate_all();
+        self.status.invalidate_all();
+    }
+
+    /// Returns the wrapped `AsyncRepository`, for operations this cache doesn't cover.
+    pub fn inner(&self) -> &AsyncRepository {
+        &self.repo
+    }
+}
@@ -73,4 +73,39 @@ pub enum GitError {
     /// Write operation attempted on a read-only repository.
     #[error("Write operation not permitted on read-only repository: {0}")]
     WriteOperationNotPermitted(String),
+
+    /// An HTTP request to a provider API failed: a transport/network error, a non-2xx
+    /// response status, or a response body that could not be deserialized.
+    #[error("HTTP request failed: {0}")]
+    Http(String),
+
+    /// A Git invocation was killed after exceeding its configured `ExecConfig::timeout`.
+    #[error("git command timed out")]
+    TimedOut,
+
+    /// A provider API request completed but returned a non-success HTTP status, distinct
+    /// from `Http` so callers can match on the status code (e.g. to detect rate limiting).
+    #[error("provider API request failed with status {0}")]
+    ApiStatus(u16),
+}
+
+/// Describes why `Repository::validate_remotes` could not confirm the repository's
+/// remote wiring matches what was expected.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RemoteMismatch {
+    /// No default fetch remote could be resolved (e.g. detached HEAD with no fallback).
+    #[error("No default fetch remote is configured")]
+    NoFetchRemote,
+
+    /// No default push remote could be resolved.
+    #[error("No default push remote is configured")]
+    NoPushRemote,
+
+    /// The resolved push and/or fetch remote doesn't match the expected remote.
+    #[error("remote mismatch: expected '{expected}', found push='{push}', fetch='{fetch}'")]
+    Mismatch {
+        expected: String,
+        push: String,
+        fetch: String,
+    },
 }
\ No newline at end of file
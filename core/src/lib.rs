@@ -10,6 +10,7 @@ pub mod types;
 pub mod models;
 pub mod repository;
 pub mod analytics;
+pub mod patch;
 
 // Add provider modules
 pub mod providers;
@@ -18,6 +19,9 @@ pub mod providers;
 #[cfg(feature = "async")]
 pub mod async_repository;
 
+#[cfg(feature = "cache")]
+pub mod cache;
+
 // Re-export key types
 pub use crate::error::GitError;
 pub use crate::repository::Repository;
@@ -35,7 +39,11 @@ pub mod prelude {
     pub use crate::types::{BranchName, GitUrl, Result};
     pub use crate::models::*;
     pub use crate::analytics::*;
+    pub use crate::patch::*;
 
     #[cfg(feature = "async")]
     pub use crate::async_repository::AsyncRepository;
+
+    #[cfg(feature = "cache")]
+    pub use crate::cache::{CacheConfig, CachedAsyncRepository};
 }
\ No newline at end of file
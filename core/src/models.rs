@@ -6,6 +6,20 @@ use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::collections::HashMap;
 
+/// The `--format` string shared by `get_commit` and `get_commit_history`.
+///
+/// `%x1e` (record separator) precedes every commit so multi-commit output can be split
+/// unambiguously with `str::split('\x1e')`, and `%x1f` (unit separator) delimits fields so
+/// multi-line subjects/bodies and merge commits with several parents can't desynchronize a
+/// fixed-line-count parser.
+pub(crate) const COMMIT_LOG_FORMAT: &str =
+    "%x1e%H%x1f%h%x1f%an%x1f%ae%x1f%at%x1f%cn%x1f%ce%x1f%ct%x1f%P%x1f%s%x1f%b";
+
+/// Same as `COMMIT_LOG_FORMAT`, but using the `%aN`/`%aE`/`%cN`/`%cE` mailmap-aware
+/// placeholders so author/committer identities are canonicalized through `.mailmap`.
+pub(crate) const COMMIT_LOG_FORMAT_MAILMAP: &str =
+    "%x1e%H%x1f%h%x1f%aN%x1f%aE%x1f%at%x1f%cN%x1f%cE%x1f%ct%x1f%P%x1f%s%x1f%b";
+
 /// Represents a Git commit.
 #[derive(Debug, Clone)]
 pub struct Commit {
@@ -17,51 +31,58 @@ pub struct Commit {
     pub author_name: String,
     /// The commit author's email.
     pub author_email: String,
-    /// The commit timestamp (seconds since Unix epoch).
+    /// The commit author timestamp (seconds since Unix epoch).
     pub timestamp: u64,
-    /// The commit message.
+    /// The committer's name.
+    pub committer_name: String,
+    /// The committer's email.
+    pub committer_email: String,
+    /// The committer timestamp (seconds since Unix epoch).
+    pub committer_timestamp: u64,
+    /// The commit message, including the subject and body.
     pub message: String,
-    /// Parent commit hashes.
+    /// Parent commit hashes (more than one for a merge commit, none for a root commit).
     pub parents: Vec<CommitHash>,
+    /// GPG/SSH signature status, if signature verification was requested.
+    pub signature: Option<SignatureStatus>,
 }
 
 impl Commit {
-    /// Parses a commit from the output of `git show --format=...`.
-    pub(crate) fn from_show_format(output: &str) -> Option<Commit> {
-        let mut hash_str = None;
-        let mut short_hash_str = None;
-        let mut author_name = String::new();
-        let mut author_email = String::new();
-        let mut timestamp = 0;
-        let mut message = String::new();
-        let mut parent_hashes_str = String::new();
+    /// Parses a single commit record produced by `COMMIT_LOG_FORMAT`.
+    ///
+    /// # Arguments
+    /// * `record` - One commit's worth of output, with the leading `%x1e` already stripped,
+    ///   containing exactly the `%x1f`-separated fields from `COMMIT_LOG_FORMAT`.
+    pub(crate) fn from_record(record: &str) -> Option<Commit> {
+        let mut fields = record.splitn(11, '\x1f');
 
-        for line in output.lines() {
-            if hash_str.is_none() && !line.is_empty() {
-                hash_str = Some(line.to_string());
-            } else if line.starts_with("shortcommit ") {
-                short_hash_str = Some(line.trim_start_matches("shortcommit ").to_string());
-            } else if line.starts_with("author_name ") {
-                author_name = line.trim_start_matches("author_name ").to_string();
-            } else if line.starts_with("author_email ") {
-                author_email = line.trim_start_matches("author_email ").to_string();
-            } else if line.starts_with("timestamp ") {
-                timestamp = line.trim_start_matches("timestamp ").parse::<u64>().ok()?;
-            } else if !line.starts_with("message ") && parent_hashes_str.is_empty() && hash_str.is_some() && short_hash_str.is_some() {
-                parent_hashes_str = line.to_string();
-            } else if line.starts_with("message ") {
-                message = line.trim_start_matches("message ").to_string();
-            }
-        }
+        let hash = CommitHash::from_str(fields.next()?).ok()?;
+        let short_hash = CommitHash::from_str(fields.next()?).ok()?;
+        let author_name = fields.next()?.to_string();
+        let author_email = fields.next()?.to_string();
+        let timestamp = fields.next()?.parse::<u64>().ok()?;
+        let committer_name = fields.next()?.to_string();
+        let committer_email = fields.next()?.to_string();
+        let committer_timestamp = fields.next()?.parse::<u64>().ok()?;
+        let parents_str = fields.next()?;
+        let subject = fields.next()?;
+        let body = fields.next().unwrap_or("");
 
-        let hash = CommitHash::from_str(&hash_str?).ok()?;
-        let short_hash = CommitHash::from_str(&short_hash_str?).ok()?;
+        let parents = if parents_str.is_empty() {
+            Vec::new()
+        } else {
+            parents_str
+                .split_whitespace()
+                .map(CommitHash::from_str)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .ok()?
+        };
 
-        let parents = parent_hashes_str
-            .split_whitespace()
-            .map(CommitHash::from_str)
-            .collect::<std::result::Result<Vec<_>, _>>()
-            .ok()?;
+        let message = if body.is_empty() {
+            subject.to_string()
+        } else {
+            format!("{}\n\n{}", subject, body.trim_end_matches('\n'))
+        };
 
         Some(Commit {
             hash,
@@ -69,8 +90,12 @@ impl Commit {
             author_name,
             author_email,
             timestamp,
+            committer_name,
+            committer_email,
+            committer_timestamp,
             message,
             parents,
+            signature: None,
         })
     }
 
@@ -80,6 +105,66 @@ impl Commit {
     }
 }
 
+/// Represents a single mapping entry from a repository's `.mailmap` file, canonicalizing a
+/// duplicate author/committer identity (e.g. a typo email or a name change) to one identity.
+#[derive(Debug, Clone)]
+pub struct MailmapEntry {
+    /// The canonical (mapped-to) name.
+    pub canonical_name: String,
+    /// The canonical (mapped-to) email.
+    pub canonical_email: String,
+    /// The original commit name being mapped, if the line constrains it.
+    pub commit_name: Option<String>,
+    /// The original commit email being mapped.
+    pub commit_email: String,
+}
+
+impl MailmapEntry {
+    /// Parses a single non-comment, non-blank `.mailmap` line.
+    ///
+    /// Supported forms (see `gitmailmap(5)`):
+    /// * `Canonical Name <canonical@email> Commit Name <commit@email>`
+    /// * `Canonical Name <canonical@email> <commit@email>`
+    /// * `<canonical@email> <commit@email>`
+    pub(crate) fn parse_line(line: &str) -> Option<MailmapEntry> {
+        let emails: Vec<&str> = line
+            .match_indices('<')
+            .filter_map(|(start, _)| {
+                let end = line[start..].find('>')? + start;
+                Some(&line[start + 1..end])
+            })
+            .collect();
+
+        if emails.is_empty() {
+            return None;
+        }
+
+        // Everything before the first '<' is the canonical name; everything between the
+        // first '>' and the second '<' (if any) is the original commit name.
+        let first_open = line.find('<')?;
+        let canonical_name = line[..first_open].trim().to_string();
+
+        let canonical_email = emails[0].to_string();
+        let commit_email = emails.get(1).unwrap_or(&emails[0]).to_string();
+
+        let commit_name = if emails.len() > 1 {
+            let first_close = line.find('>')? + 1;
+            let second_open = line[first_close..].find('<')? + first_close;
+            let name = line[first_close..second_open].trim();
+            if name.is_empty() { None } else { Some(name.to_string()) }
+        } else {
+            None
+        };
+
+        Some(MailmapEntry {
+            canonical_name,
+            canonical_email,
+            commit_name,
+            commit_email,
+        })
+    }
+}
+
 /// Represents a file status from `git status`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileStatus {
@@ -122,6 +207,56 @@ pub struct StatusEntry {
     pub original_path: Option<PathBuf>,
 }
 
+/// Represents the kind of a raw Git object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitObjectKind {
+    Blob,
+    Tree,
+    Commit,
+    Tag,
+}
+
+impl FromStr for GitObjectKind {
+    type Err = crate::error::GitError;
+
+    fn from_str(s: &str) -> crate::types::Result<Self> {
+        match s {
+            "blob" => Ok(GitObjectKind::Blob),
+            "tree" => Ok(GitObjectKind::Tree),
+            "commit" => Ok(GitObjectKind::Commit),
+            "tag" => Ok(GitObjectKind::Tag),
+            other => Err(crate::error::GitError::AnalysisError(format!(
+                "Unknown git object kind: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Represents a raw Git object read via `git cat-file`.
+#[derive(Debug, Clone)]
+pub struct GitObject {
+    /// The object's kind (blob, tree, commit, or tag).
+    pub kind: GitObjectKind,
+    /// The object's size in bytes, as reported by Git.
+    pub size: usize,
+    /// The object's raw, uninterpreted content.
+    pub data: Vec<u8>,
+}
+
+/// Represents a single entry in a Git tree, as parsed from `git ls-tree`.
+#[derive(Debug, Clone)]
+pub struct TreeEntry {
+    /// The file mode (e.g. `100644`, `40000`).
+    pub mode: String,
+    /// The kind of object this entry points to.
+    pub kind: GitObjectKind,
+    /// The object ID this entry points to.
+    pub oid: CommitHash,
+    /// The entry's name (file or directory name), relative to the tree being listed.
+    pub name: String,
+}
+
 /// Represents a Git tag.
 #[derive(Debug, Clone)]
 pub struct TagInfo {
@@ -133,6 +268,155 @@ pub struct TagInfo {
     pub annotated: bool,
     /// For annotated tags, the tag message.
     pub message: Option<String>,
+    /// GPG/SSH signature status, if signature verification was requested.
+    pub signature: Option<SignatureStatus>,
+}
+
+/// Represents the outcome of verifying a GPG/SSH signature on a commit or tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureStatus {
+    /// The overall verification state.
+    pub state: SignatureState,
+    /// The signer's identity, if reported.
+    pub signer: Option<String>,
+    /// The signing key's identifier, if reported.
+    pub key_id: Option<String>,
+}
+
+/// Represents the verification state of a GPG/SSH signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureState {
+    /// A valid signature from a trusted key.
+    Good,
+    /// A signature was present but failed verification.
+    Bad,
+    /// A signature was present but could not be verified (e.g. no public key).
+    Unknown,
+    /// No signature was present.
+    None,
+}
+
+impl SignatureStatus {
+    /// Parses the `gpg:`-prefixed lines emitted by `git verify-commit --raw` /
+    /// `git verify-tag --raw` / `git show --show-signature` into a `SignatureStatus`.
+    pub(crate) fn from_gpg_output(output: &str) -> SignatureStatus {
+        let mut state = SignatureState::None;
+        let mut signer = None;
+        let mut key_id = None;
+
+        for line in output.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("gpg: Signature made") {
+                // No direct data here, but a signature is present until proven otherwise.
+                let _ = rest;
+                if state == SignatureState::None {
+                    state = SignatureState::Unknown;
+                }
+            } else if let Some(rest) = line.strip_prefix("gpg: Good signature from ") {
+                state = SignatureState::Good;
+                signer = Some(rest.trim_matches('"').to_string());
+            } else if line.starts_with("gpg: BAD signature from") {
+                state = SignatureState::Bad;
+            } else if line.starts_with("gpg: Can't check signature") || line.contains("No public key") {
+                state = SignatureState::Unknown;
+            } else if let Some(rest) = line.strip_prefix("gpg:                using ") {
+                // e.g. "RSA key 0123456789ABCDEF"
+                if let Some(id) = rest.split_whitespace().last() {
+                    key_id = Some(id.to_string());
+                }
+            }
+        }
+
+        SignatureStatus {
+            state,
+            signer,
+            key_id,
+        }
+    }
+}
+
+/// Represents the kind of a ref reported by `git ls-remote`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteRefKind {
+    Branch,
+    Tag,
+    /// The dereferenced commit an annotated tag points to (a `<tag>^{}` entry).
+    Peeled,
+    Head,
+}
+
+/// Represents a single ref reported by `git ls-remote`.
+#[derive(Debug, Clone)]
+pub struct RemoteRef {
+    /// The short ref name (branch/tag name, or `HEAD`).
+    pub name: String,
+    /// The object ID the ref currently points to.
+    pub oid: CommitHash,
+    /// The kind of ref this entry represents.
+    pub kind: RemoteRefKind,
+}
+
+/// Represents a single ref update reported by `git fetch`.
+#[derive(Debug, Clone)]
+pub struct RefUpdate {
+    /// The name of the updated remote-tracking ref.
+    pub name: String,
+    /// The ref's prior object ID, if it already existed locally.
+    pub old: Option<CommitHash>,
+    /// The ref's new object ID after the fetch.
+    pub new: CommitHash,
+    /// Whether the update was a non-fast-forward (forced) update.
+    pub forced: bool,
+}
+
+/// Transfer statistics parsed from the `--progress` output of a `git fetch`/`pull`/`push`.
+///
+/// `received_objects`/`received_bytes` track whichever side of the transfer the running
+/// command reports (the "Receiving objects"/"Writing objects" stage), so the same struct
+/// serves both directions.
+#[derive(Debug, Clone, Default)]
+pub struct TransferStats {
+    /// Total number of objects reported for the transfer.
+    pub total_objects: usize,
+    /// Objects sent or received so far.
+    pub received_objects: usize,
+    /// Objects indexed so far (fetch only; git indexes the objects it receives).
+    pub indexed_objects: usize,
+    /// Bytes transferred, parsed from e.g. `1.23 MiB`.
+    pub received_bytes: u64,
+    /// Objects reused from the local object store instead of being transferred.
+    pub local_objects: usize,
+}
+
+/// A single progress update parsed from a `git fetch`/`pull`/`push`'s `--progress` output,
+/// passed to the caller's progress callback as lines stream in.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    /// The raw progress line as reported by git (e.g. `"Receiving objects:  42% (210/500)"`).
+    pub message: String,
+    /// The percentage completion, if this line reported one.
+    pub percent: Option<u8>,
+}
+
+/// Summarizes the ref changes produced by a `git fetch`.
+#[derive(Debug, Clone)]
+pub struct FetchSummary {
+    /// Existing refs that were moved by the fetch. Newly created refs are not listed,
+    /// since `git fetch` reports no object ID for them.
+    pub updated_refs: Vec<RefUpdate>,
+    /// Remote-tracking refs that were pruned because they no longer exist on the remote.
+    pub pruned: Vec<String>,
+}
+
+/// Ahead/behind tracking status for a single remote-tracking branch.
+#[derive(Debug, Clone)]
+pub struct RemoteBranchStatus {
+    /// The remote-tracking branch's short name (e.g. `origin/main`).
+    pub name: String,
+    /// Commits on the local tracking branch not yet on the remote.
+    pub ahead: usize,
+    /// Commits on the remote not yet on the local tracking branch.
+    pub behind: usize,
 }
 
 /// Represents a Git remote.
@@ -144,6 +428,9 @@ pub struct RemoteInfo {
     pub url: GitUrl,
     /// The fetch refspec.
     pub fetch: Option<String>,
+    /// Whether this remote was identified as the project's canonical upstream,
+    /// e.g. via `Repository::find_upstream_remote`.
+    pub is_upstream: bool,
 }
 
 /// Represents a Git branch.
@@ -157,6 +444,8 @@ pub struct Branch {
     pub is_head: bool,
     /// The upstream branch ref string (e.g., "origin/main").
     pub upstream: Option<String>,
+    /// The tip commit's timestamp (seconds since Unix epoch), if available.
+    pub last_commit_timestamp: Option<u64>,
 }
 
 /// Represents the result of a `git status` command.
@@ -199,11 +488,29 @@ pub struct DiffResult {
     pub files: Vec<DiffFile>,
 }
 
+/// A single file's line-count summary from a `git diff --numstat`, as returned by
+/// `AsyncRepository::diff_stat`/`diff_commit`.
+#[derive(Debug, Clone)]
+pub struct FileDiffStat {
+    /// The file path, relative to the repository root.
+    pub path: PathBuf,
+    /// Number of added lines, or `0` for a binary file.
+    pub additions: usize,
+    /// Number of removed lines, or `0` for a binary file.
+    pub deletions: usize,
+    /// Whether `git` reported this file as binary (numstat prints `-` for both counts).
+    pub binary: bool,
+}
+
 /// Represents a file in a diff.
 #[derive(Debug, Clone)]
 pub struct DiffFile {
     pub path: PathBuf,
     pub old_path: Option<PathBuf>,
+    /// How the file was changed between the two sides of the diff.
+    pub change_kind: ChangeKind,
+    /// Similarity percentage (0-100) reported for renames/copies.
+    pub similarity: Option<u8>,
     pub hunks: Vec<DiffHunk>,
     pub added_lines: usize,
     pub removed_lines: usize,
@@ -212,6 +519,16 @@ pub struct DiffFile {
     pub new_mode: Option<String>,
 }
 
+/// Represents how a file changed between the two sides of a diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Deleted,
+    Modified,
+    Renamed,
+    Copied,
+}
+
 /// Represents a hunk in a diff.
 #[derive(Debug, Clone)]
 pub struct DiffHunk {
@@ -219,6 +536,8 @@ pub struct DiffHunk {
     pub old_lines: usize,
     pub new_start: usize,
     pub new_lines: usize,
+    /// The raw `@@ ... @@` header line, including any trailing section heading.
+    pub header: String,
     pub lines: Vec<DiffLine>,
 }
 
@@ -355,4 +674,10 @@ pub struct BranchDivergence {
     pub behind_count: usize,
     /// Files that differ between branches.
     pub differing_files: usize,
+    /// Paths that could not be auto-merged in a no-commit merge preview of `source` and
+    /// `target` at their `merge_base`.
+    pub conflicting_files: Vec<PathBuf>,
+    /// Whether merging `target` into `source` could fast-forward (i.e. `source` has no
+    /// commits `target` doesn't already have).
+    pub can_fast_forward: bool,
 }
\ No newline at end of file
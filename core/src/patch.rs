@@ -0,0 +1,312 @@
+//! Renders `DiffResult` models back into unified diff text and exports/applies commit ranges
+//! as mailbox-style patch series, making the diff models round-trippable for code-review and
+//! transport workflows.
+
+use crate::error::GitError;
+use crate::models::{ChangeKind, DiffFile, DiffHunk, DiffLineType, DiffResult};
+use crate::repository::{execute_git_fn, Repository};
+use crate::types::{CommitHash, Result};
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Renders a `DiffResult` back into unified diff text equivalent to `git diff`'s output.
+pub fn render_diff(diff: &DiffResult) -> String {
+    diff.files.iter().map(render_diff_file).collect()
+}
+
+/// Renders a single `DiffFile` into unified diff text, including its `diff --git a/… b/…`
+/// header, rename/copy/mode markers, and hunks.
+pub fn render_diff_file(file: &DiffFile) -> String {
+    let mut out = String::new();
+
+    let old_path = file.old_path.as_ref().unwrap_or(&file.path);
+    let a_path = old_path.display();
+    let b_path = file.path.display();
+
+    out.push_str(&format!("diff --git a/{} b/{}\n", a_path, b_path));
+
+    match file.change_kind {
+        ChangeKind::Renamed => {
+            out.push_str(&format!("rename from {}\n", a_path));
+            out.push_str(&format!("rename to {}\n", b_path));
+        }
+        ChangeKind::Copied => {
+            out.push_str(&format!("copy from {}\n", a_path));
+            out.push_str(&format!("copy to {}\n", b_path));
+        }
+        ChangeKind::Added => {
+            if let Some(new_mode) = &file.new_mode {
+                out.push_str(&format!("new file mode {}\n", new_mode));
+            }
+        }
+        ChangeKind::Deleted => {
+            if let Some(old_mode) = &file.old_mode {
+                out.push_str(&format!("deleted file mode {}\n", old_mode));
+            }
+        }
+        ChangeKind::Modified => {
+            if let (Some(old_mode), Some(new_mode)) = (&file.old_mode, &file.new_mode) {
+                if old_mode != new_mode {
+                    out.push_str(&format!("old mode {}\n", old_mode));
+                    out.push_str(&format!("new mode {}\n", new_mode));
+                }
+            }
+        }
+    }
+
+    if let Some(similarity) = file.similarity {
+        out.push_str(&format!("similarity index {}%\n", similarity));
+    }
+
+    if file.is_binary {
+        out.push_str("Binary files differ\n");
+        return out;
+    }
+
+    if file.hunks.is_empty() {
+        return out;
+    }
+
+    match file.change_kind {
+        ChangeKind::Added => out.push_str(&format!("--- /dev/null\n+++ b/{}\n", b_path)),
+        ChangeKind::Deleted => out.push_str(&format!("--- a/{}\n+++ /dev/null\n", a_path)),
+        _ => out.push_str(&format!("--- a/{}\n+++ b/{}\n", a_path, b_path)),
+    }
+
+    for hunk in &file.hunks {
+        out.push_str(&render_diff_hunk(hunk));
+    }
+
+    out
+}
+
+/// Renders a single `DiffHunk`, reusing its stored `@@ -old_start,old_lines +new_start,new_lines @@`
+/// header verbatim.
+pub fn render_diff_hunk(hunk: &DiffHunk) -> String {
+    let mut out = String::new();
+    out.push_str(&hunk.header);
+    out.push('\n');
+
+    for line in &hunk.lines {
+        let marker = match line.line_type {
+            DiffLineType::Context => ' ',
+            DiffLineType::Added => '+',
+            DiffLineType::Removed => '-',
+        };
+        out.push(marker);
+        out.push_str(&line.content);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// A single patch in a mailbox-style export of a commit range, as produced by
+/// `Repository::export_patch_series`.
+#[derive(Debug, Clone)]
+pub struct PatchEntry {
+    /// The commit hash this patch was generated from.
+    pub hash: CommitHash,
+    /// The commit author's name.
+    pub author_name: String,
+    /// The commit author's email.
+    pub author_email: String,
+    /// The author timestamp (seconds since Unix epoch).
+    pub timestamp: u64,
+    /// The commit message (subject and body).
+    pub message: String,
+    /// The unified diff text for this commit, suitable for `Repository::apply_patch_series`.
+    pub diff_text: String,
+}
+
+/// The result of applying, or dry-run checking, a patch series.
+#[derive(Debug, Clone)]
+pub struct ApplyResult {
+    /// Whether every patch in the series applied cleanly.
+    pub success: bool,
+    /// Diagnostic lines (one per failing hunk/file) reported by `git apply` when `success` is
+    /// `false`.
+    pub failed_hunks: Vec<String>,
+}
+
+impl Repository {
+    /// Exports a commit range as a mailbox-style patch series, one `PatchEntry` per commit.
+    ///
+    /// Equivalent to `git format-patch --stdout <range>`, with author/timestamp cross-referenced
+    /// from `git log` so the mailbox `Date:` header never needs to be parsed.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn export_patch_series(&self, range: &str) -> Result<Vec<PatchEntry>> {
+        let metadata_output = execute_git_fn(
+            &self.location,
+            &["log", "--format=%H%x1f%an%x1f%ae%x1f%at", range],
+            |output| Ok(output.to_string()),
+        )?;
+
+        let mut metadata: HashMap<String, (String, String, u64)> = HashMap::new();
+        for line in metadata_output.lines() {
+            let mut fields = line.splitn(4, '\x1f');
+            let hash = fields.next().unwrap_or("").to_string();
+            let author_name = fields.next().unwrap_or("").to_string();
+            let author_email = fields.next().unwrap_or("").to_string();
+            let timestamp: u64 = fields.next().unwrap_or("0").parse().unwrap_or(0);
+            metadata.insert(hash, (author_name, author_email, timestamp));
+        }
+
+        let patch_output = execute_git_fn(
+            &self.location,
+            &["format-patch", "--stdout", "--no-signature", range],
+            |output| Ok(output.to_string()),
+        )?;
+
+        Ok(parse_patch_series(&patch_output, &metadata))
+    }
+
+    /// Applies a patch series produced by `export_patch_series` to the working tree and index,
+    /// or just checks whether it would apply cleanly when `dry_run` is `true`.
+    ///
+    /// Equivalent to `git apply --index [--check] <patch-file>`. Unlike `git am`, this never creates
+    /// commits; it only stages the changes, leaving the caller to commit them.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`) for failures unrelated to patch
+    /// application itself (e.g. the repository being inaccessible). Patches that fail to
+    /// apply are reported through `ApplyResult::failed_hunks` rather than as an `Err`.
+    pub fn apply_patch_series(&self, patches: &[PatchEntry], dry_run: bool) -> Result<ApplyResult> {
+        let combined = patches
+            .iter()
+            .map(|patch| patch.diff_text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut patch_file = std::env::temp_dir();
+        patch_file.push(format!("gitlens-patch-series-{}.diff", std::process::id()));
+        std::fs::write(&patch_file, combined).map_err(|e| GitError::FileSystemError(e.to_string()))?;
+
+        let patch_file_str = patch_file.to_string_lossy().to_string();
+        let mut args = vec!["apply", "--index"];
+        if dry_run {
+            args.push("--check");
+        }
+        args.push(&patch_file_str);
+
+        let result = execute_git_fn(&self.location, &args, |output| Ok(output.to_string()));
+        let _ = std::fs::remove_file(&patch_file);
+
+        match result {
+            Ok(_) => Ok(ApplyResult { success: true, failed_hunks: Vec::new() }),
+            Err(GitError::GitError { stderr, .. }) => {
+                let failed_hunks = stderr
+                    .lines()
+                    .filter(|line| line.starts_with("error:") || line.contains("patch failed"))
+                    .map(|line| line.to_string())
+                    .collect();
+                Ok(ApplyResult { success: false, failed_hunks })
+            }
+            Err(other) => Err(other),
+        }
+    }
+}
+
+/// Splits `git format-patch --stdout`'s mbox output into individual `PatchEntry`s, one per
+/// message boundary (a `From <40-hex-hash> <date>` line).
+fn parse_patch_series(output: &str, metadata: &HashMap<String, (String, String, u64)>) -> Vec<PatchEntry> {
+    let mut entries = Vec::new();
+    let mut current = String::new();
+
+    for line in output.lines() {
+        if is_mbox_from_line(line) && !current.is_empty() {
+            if let Some(entry) = parse_patch_entry(&current, metadata) {
+                entries.push(entry);
+            }
+            current.clear();
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+
+    if !current.trim().is_empty() {
+        if let Some(entry) = parse_patch_entry(&current, metadata) {
+            entries.push(entry);
+        }
+    }
+
+    entries
+}
+
+/// Whether `line` is an mbox message-boundary line, i.e. `From <40-hex-hash> <date>`.
+fn is_mbox_from_line(line: &str) -> bool {
+    match line.strip_prefix("From ") {
+        Some(rest) => rest.get(..40).map_or(false, |h| h.chars().all(|c| c.is_ascii_hexdigit())),
+        None => false,
+    }
+}
+
+/// Parses a single mbox message block into a `PatchEntry`, using `metadata` for the
+/// author/timestamp fields rather than parsing the RFC 2822 `Date:` header.
+fn parse_patch_entry(block: &str, metadata: &HashMap<String, (String, String, u64)>) -> Option<PatchEntry> {
+    let mut lines = block.lines();
+    let header_line = lines.next()?;
+    let hash_str = header_line.strip_prefix("From ")?.split_whitespace().next()?;
+    let hash = CommitHash::from_str(hash_str).ok()?;
+
+    let mut subject = String::new();
+    let mut body_lines: Vec<&str> = Vec::new();
+    let mut in_headers = true;
+
+    for line in lines {
+        if in_headers {
+            if let Some(rest) = line.strip_prefix("Subject: ") {
+                subject = strip_subject_prefix(rest);
+            } else if line.is_empty() {
+                in_headers = false;
+            }
+        } else {
+            body_lines.push(line);
+        }
+    }
+
+    // The commit message body runs until the lone "---" line introducing the diffstat, which
+    // precedes the blank line and the `diff --git` hunks.
+    let separator = body_lines.iter().position(|line| *line == "---");
+    let (message_lines, diffstat_lines) = match separator {
+        Some(index) => (&body_lines[..index], &body_lines[index + 1..]),
+        None => (&body_lines[..], &body_lines[0..0]),
+    };
+
+    let mut message = subject;
+    let body = message_lines.join("\n").trim().to_string();
+    if !body.is_empty() {
+        message.push_str("\n\n");
+        message.push_str(&body);
+    }
+
+    let diff_start = diffstat_lines.iter().position(|line| line.starts_with("diff --git "));
+    let diff_text = match diff_start {
+        Some(index) => diffstat_lines[index..].join("\n"),
+        None => String::new(),
+    };
+
+    let (author_name, author_email, timestamp) = metadata.get(hash_str).cloned().unwrap_or_default();
+
+    Some(PatchEntry {
+        hash,
+        author_name,
+        author_email,
+        timestamp,
+        message,
+        diff_text,
+    })
+}
+
+/// Strips the `[PATCH]`/`[PATCH n/m]` prefix `git format-patch` adds to the `Subject:` header.
+fn strip_subject_prefix(subject: &str) -> String {
+    if subject.starts_with('[') {
+        if let Some(end) = subject.find("] ") {
+            return subject[end + 2..].to_string();
+        }
+    }
+    subject.to_string()
+}
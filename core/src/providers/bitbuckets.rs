@@ -2,14 +2,18 @@
 
 use crate::error::GitError;
 use crate::types::Result;
+#[cfg(feature = "async")]
+use crate::providers::AsyncProviderClient;
 use crate::providers::{
-    ProviderOperations, ProviderClient, ProviderType,
+    parse_rfc3339_timestamp,
+    Auth, ClientOptions, CreateIssue, CreatePullRequest, ListOptions, ProviderOperations, ProviderClient, ProviderType,
     PullRequest, PullRequestState, Issue, IssueState, RepositoryInfo,
 };
 
 use std::sync::Arc;
 use regex::Regex;
 use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 // Regular expression to match Bitbucket URLs
 static BITBUCKET_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -20,19 +24,42 @@ static BITBUCKET_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
 /// Bitbucket provider implementation.
 pub struct BitbucketProvider {
     api_url: String,
+    url_regex: Regex,
 }
 
 impl BitbucketProvider {
-    /// Creates a new Bitbucket provider.
+    /// Creates a new Bitbucket provider for the public bitbucket.org host.
     pub fn new() -> Self {
         BitbucketProvider {
             api_url: "https://api.bitbucket.org/2.0".to_string(),
+            url_regex: BITBUCKET_URL_REGEX.clone(),
+        }
+    }
+
+    /// Creates a Bitbucket provider for a self-hosted Bitbucket Server/Data Center instance.
+    ///
+    /// # Arguments
+    /// * `host` - The web host, e.g. `bitbucket.mycorp.com`.
+    /// * `api_url` - The base API URL, e.g. `https://bitbucket.mycorp.com/rest/api/1.0`.
+    ///
+    /// The client builds Bitbucket Cloud 2.0 style paths (`/repositories/{owner}/{repo}/...`)
+    /// under `api_url`; Bitbucket Server's `/projects/{key}/repos/{slug}/...` REST paths are
+    /// not used.
+    pub fn with_host(host: &str, api_url: &str) -> Self {
+        let pattern = format!(
+            r"(?i)^(?:https?://(?:www\.)?{0}/|git@{0}:)([^/]+)/([^/]+?)(?:\.git)?/?$",
+            regex::escape(host)
+        );
+
+        BitbucketProvider {
+            api_url: api_url.to_string(),
+            url_regex: Regex::new(&pattern).expect("Invalid Bitbucket URL regex"),
         }
     }
 
     /// Extracts owner and repository name from a Bitbucket URL.
     pub fn parse_url(&self, url: &str) -> Result<(String, String)> {
-        if let Some(captures) = BITBUCKET_URL_REGEX.captures(url) {
+        if let Some(captures) = self.url_regex.captures(url) {
             let owner = captures.get(1).unwrap().as_str().to_string();
             let repo = captures.get(2).unwrap().as_str().to_string();
             Ok((owner, repo))
@@ -52,13 +79,56 @@ impl ProviderOperations for BitbucketProvider {
     }
 
     fn matches_url(&self, url: &str) -> bool {
-        BITBUCKET_URL_REGEX.is_match(url)
+        self.url_regex.is_match(url)
+    }
+
+    fn parse_owner_repo(&self, url: &str) -> Result<(String, String)> {
+        self.parse_url(url)
     }
 
-    fn create_client(&self, auth_token: Option<String>) -> Result<Box<dyn ProviderClient>> {
+    fn create_client(&self, auth: Option<Auth>) -> Result<Box<dyn ProviderClient>> {
+        self.create_client_with_options(ClientOptions {
+            auth,
+            ..Default::default()
+        })
+    }
+
+    fn create_client_with_options(&self, options: ClientOptions) -> Result<Box<dyn ProviderClient>> {
+        let mut builder = reqwest::blocking::Client::builder();
+
+        if let Some(pem) = &options.root_certificate_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| GitError::Http(e.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http_client = builder.build().map_err(|e| GitError::Http(e.to_string()))?;
+
         Ok(Box::new(BitbucketClient {
             provider: Arc::new(self.clone()),
-            auth_token,
+            auth: options.auth,
+            http_client,
+        }))
+    }
+
+    fn clone_box(&self) -> Box<dyn ProviderOperations> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_client(&self, options: ClientOptions) -> Result<Box<dyn AsyncProviderClient>> {
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(pem) = &options.root_certificate_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| GitError::Http(e.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http_client = builder.build().map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(Box::new(BitbucketAsyncClient {
+            provider: Arc::new(self.clone()),
+            auth: options.auth,
+            http_client,
         }))
     }
 }
@@ -67,14 +137,73 @@ impl Clone for BitbucketProvider {
     fn clone(&self) -> Self {
         BitbucketProvider {
             api_url: self.api_url.clone(),
+            url_regex: self.url_regex.clone(),
         }
     }
 }
 
+/// A single page of a Bitbucket Cloud list endpoint, e.g. `GET .../pullrequests`.
+/// Pagination is cursor-based: `next`, when present, is the full URL of the next page.
+#[derive(Debug, Deserialize)]
+struct BitbucketPage<T> {
+    values: Vec<T>,
+    next: Option<String>,
+}
+
 /// Bitbucket client implementation.
 pub struct BitbucketClient {
     provider: Arc<BitbucketProvider>,
-    auth_token: Option<String>,
+    auth: Option<Auth>,
+    http_client: reqwest::blocking::Client,
+}
+
+impl BitbucketClient {
+    /// Attaches credentials to `request` in whichever form `self.auth` holds. Bitbucket
+    /// Cloud accepts either a repository/workspace access token as a Bearer token, or a
+    /// username and app password via HTTP Basic auth.
+    fn authenticate(&self, request: reqwest::blocking::RequestBuilder) -> reqwest::blocking::RequestBuilder {
+        match &self.auth {
+            Some(Auth::Token(token)) | Some(Auth::CiJobToken(token)) => {
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+            Some(Auth::UsernamePassword(username, password)) => request.basic_auth(username, Some(password)),
+            None => request,
+        }
+    }
+
+    /// Builds a GET request against the Bitbucket API.
+    fn get(&self, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        self.authenticate(self.http_client.get(&url))
+    }
+
+    /// Builds a POST request against the Bitbucket API.
+    fn post(&self, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        self.authenticate(self.http_client.post(&url))
+    }
+
+    /// Fetches every page of a Bitbucket list endpoint starting at `path`, following each
+    /// page's `next` link (an absolute URL) until the response omits one.
+    fn get_all_pages<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<Vec<T>> {
+        let mut all = Vec::new();
+        let mut request = Some(self.get(path).query(query));
+
+        while let Some(req) = request.take() {
+            let response = req.send().map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::ApiStatus(response.status().as_u16()));
+            }
+
+            let page: BitbucketPage<T> = response.json().map_err(|e| GitError::Http(e.to_string()))?;
+            all.extend(page.values);
+
+            request = page.next.map(|next_url| self.authenticate(self.http_client.get(next_url)));
+        }
+
+        Ok(all)
+    }
 }
 
 impl ProviderClient for BitbucketClient {
@@ -83,91 +212,513 @@ impl ProviderClient for BitbucketClient {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.auth_token.is_some()
+        self.auth.is_some()
     }
 
-    fn get_pull_requests(&self, owner: &str, repo: &str) -> Result<Vec<PullRequest>> {
-        // To implement this properly, use an HTTP client to call the Bitbucket API
-        // For now, return a placeholder with error if not authenticated
+    fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for Bitbucket API".to_string(),
             ));
         }
 
-        // In Bitbucket, pull requests are also called "pull requests"
-        // Placeholder - in a real implementation, would call Bitbucket API
-        let prs = vec![
-            // Example PRs for testing
-            PullRequest {
-                id: 1,
-                number: 1,
-                title: "Example pull request".to_string(),
-                description: Some("This is an example PR description".to_string()),
-                state: PullRequestState::Open,
-                author: "example-user".to_string(),
-                created_at: 1617235200, // Example timestamp
-                updated_at: 1617235200,
-                source_branch: "feature-branch".to_string(),
-                target_branch: "main".to_string(),
-                url: format!("https://bitbucket.org/{}/{}/pull-requests/1", owner, repo),
-            },
-        ];
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("OPEN").to_uppercase();
+        let per_page = options.per_page.unwrap_or(50).to_string();
+        let path = format!("/repositories/{}/{}/pullrequests", owner, repo);
+
+        let raw: Vec<BitbucketPullRequest> =
+            self.get_all_pages(&path, &[("state", state.as_str()), ("pagelen", per_page.as_str())])?;
 
-        Ok(prs)
+        Ok(raw.into_iter().map(BitbucketPullRequest::into_pull_request).collect())
     }
 
-    fn get_issues(&self, owner: &str, repo: &str) -> Result<Vec<Issue>> {
-        // To implement this properly, use an HTTP client to call the Bitbucket API
-        // For now, return a placeholder with error if not authenticated
+    fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for Bitbucket API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call Bitbucket API
-        let issues = vec![
-            // Example issues for testing
-            Issue {
-                id: 1,
-                number: 1,
-                title: "Example issue".to_string(),
-                description: Some("This is an example issue description".to_string()),
-                state: IssueState::Open,
-                author: "example-user".to_string(),
-                created_at: 1617235200, // Example timestamp
-                updated_at: 1617235200,
-                url: format!("https://bitbucket.org/{}/{}/issues/1", owner, repo),
-            },
-        ];
+        let options = options.unwrap_or_default();
+        let per_page = options.per_page.unwrap_or(50).to_string();
+        let path = format!("/repositories/{}/{}/issues", owner, repo);
+
+        // Bitbucket's `q` parameter is a BBQL expression, not a bare state value, so a
+        // caller-supplied state needs quoting into `state="<value>"` using the tracker's
+        // lowercase states (new/open/resolved/closed) rather than being passed through as-is.
+        let mut query = vec![("pagelen", per_page.as_str())];
+        let bbql_filter = options
+            .state
+            .as_deref()
+            .map(|state| format!("state=\"{}\"", state.to_lowercase()));
+        if let Some(filter) = bbql_filter.as_deref() {
+            query.push(("q", filter));
+        }
 
-        Ok(issues)
+        let raw: Vec<BitbucketIssue> = self.get_all_pages(&path, &query)?;
+
+        Ok(raw.into_iter().map(BitbucketIssue::into_issue).collect())
     }
 
     fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
-        // To implement this properly, use an HTTP client to call the Bitbucket API
-        // For now, return a placeholder with error if not authenticated
+        // Repository metadata is a public-read endpoint, so this is served without
+        // requiring authentication.
+        let response = self
+            .get(&format!("/repositories/{}/{}", owner, repo))
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::ApiStatus(response.status().as_u16()));
+        }
+
+        let raw: BitbucketRepository = response.json().map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_repository_info())
+    }
+
+    fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating a pull request requires an authenticated Bitbucket client".to_string(),
+            ));
+        }
+
+        let body = BitbucketCreatePullRequest {
+            title: request.title,
+            description: request.description,
+            source: BitbucketBranchRef {
+                branch: BitbucketBranchName {
+                    name: request.source_branch,
+                },
+            },
+            destination: BitbucketBranchRef {
+                branch: BitbucketBranchName {
+                    name: request.target_branch,
+                },
+            },
+        };
+
+        let response = self
+            .post(&format!("/repositories/{}/{}/pullrequests", owner, repo))
+            .json(&body)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::ApiStatus(response.status().as_u16()));
+        }
+
+        let raw: BitbucketPullRequest = response.json().map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_pull_request())
+    }
+
+    fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating an issue requires an authenticated Bitbucket client".to_string(),
+            ));
+        }
+
+        let body = BitbucketCreateIssue {
+            title: request.title,
+            content: request.description.map(|raw| BitbucketContent { raw }),
+        };
+
+        let response = self
+            .post(&format!("/repositories/{}/{}/issues", owner, repo))
+            .json(&body)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::ApiStatus(response.status().as_u16()));
+        }
+
+        let raw: BitbucketIssue = response.json().map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_issue())
+    }
+}
+
+/// Bitbucket client implementation backed by an async HTTP client, for callers
+/// integrating provider lookups into a tokio-based pipeline.
+///
+/// Only available when the `async` feature is enabled.
+#[cfg(feature = "async")]
+pub struct BitbucketAsyncClient {
+    provider: Arc<BitbucketProvider>,
+    auth: Option<Auth>,
+    http_client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl BitbucketAsyncClient {
+    /// Attaches credentials to `request` the same way `BitbucketClient::authenticate` does.
+    fn authenticate(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.auth {
+            Some(Auth::Token(token)) | Some(Auth::CiJobToken(token)) => {
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+            Some(Auth::UsernamePassword(username, password)) => request.basic_auth(username, Some(password)),
+            None => request,
+        }
+    }
+
+    /// Builds a GET request against the Bitbucket API.
+    fn get(&self, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        self.authenticate(self.http_client.get(&url))
+    }
+
+    /// Builds a POST request against the Bitbucket API.
+    fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        self.authenticate(self.http_client.post(&url))
+    }
+
+    /// Fetches every page of a Bitbucket list endpoint starting at `path`, following each
+    /// page's `next` link (an absolute URL) until the response omits one.
+    async fn get_all_pages<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<Vec<T>> {
+        let mut all = Vec::new();
+        let mut next_url: Option<String> = None;
+        let mut first = true;
+
+        loop {
+            let request = if first {
+                first = false;
+                self.get(path).query(query)
+            } else {
+                match &next_url {
+                    Some(url) => self.authenticate(self.http_client.get(url.as_str())),
+                    None => break,
+                }
+            };
+
+            let response = request.send().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::ApiStatus(response.status().as_u16()));
+            }
+
+            let page: BitbucketPage<T> = response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+            all.extend(page.values);
+            next_url = page.next;
+
+            if next_url.is_none() {
+                break;
+            }
+        }
+
+        Ok(all)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncProviderClient for BitbucketAsyncClient {
+    fn provider_type(&self) -> ProviderType {
+        ProviderType::Bitbucket
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    async fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for Bitbucket API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call Bitbucket API
-        let repo_info = RepositoryInfo {
-            name: repo.to_string(),
-            owner: owner.to_string(),
-            description: Some("Repository description".to_string()),
-            stars: 0,
-            forks: 0,
-            created_at: 1617235200, // Example timestamp
-            updated_at: 1617235200,
-            default_branch: "main".to_string(),
-            homepage: None,
-            url: format!("https://bitbucket.org/{}/{}", owner, repo),
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("OPEN").to_uppercase();
+        let per_page = options.per_page.unwrap_or(50).to_string();
+        let path = format!("/repositories/{}/{}/pullrequests", owner, repo);
+
+        let raw: Vec<BitbucketPullRequest> =
+            self.get_all_pages(&path, &[("state", state.as_str()), ("pagelen", per_page.as_str())]).await?;
+
+        Ok(raw.into_iter().map(BitbucketPullRequest::into_pull_request).collect())
+    }
+
+    async fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>> {
+        if !self.is_authenticated() {
+            return Err(GitError::AnalysisError(
+                "Authentication required for Bitbucket API".to_string(),
+            ));
+        }
+
+        let options = options.unwrap_or_default();
+        let per_page = options.per_page.unwrap_or(50).to_string();
+        let path = format!("/repositories/{}/{}/issues", owner, repo);
+
+        // Bitbucket's `q` parameter is a BBQL expression, not a bare state value, so a
+        // caller-supplied state needs quoting into `state="<value>"` using the tracker's
+        // lowercase states (new/open/resolved/closed) rather than being passed through as-is.
+        let mut query = vec![("pagelen", per_page.as_str())];
+        let bbql_filter = options
+            .state
+            .as_deref()
+            .map(|state| format!("state=\"{}\"", state.to_lowercase()));
+        if let Some(filter) = bbql_filter.as_deref() {
+            query.push(("q", filter));
+        }
+
+        let raw: Vec<BitbucketIssue> = self.get_all_pages(&path, &query).await?;
+
+        Ok(raw.into_iter().map(BitbucketIssue::into_issue).collect())
+    }
+
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
+        // Repository metadata is a public-read endpoint, so this is served without
+        // requiring authentication.
+        let response = self
+            .get(&format!("/repositories/{}/{}", owner, repo))
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::ApiStatus(response.status().as_u16()));
+        }
+
+        let raw: BitbucketRepository = response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_repository_info())
+    }
+
+    async fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating a pull request requires an authenticated Bitbucket client".to_string(),
+            ));
+        }
+
+        let body = BitbucketCreatePullRequest {
+            title: request.title,
+            description: request.description,
+            source: BitbucketBranchRef {
+                branch: BitbucketBranchName {
+                    name: request.source_branch,
+                },
+            },
+            destination: BitbucketBranchRef {
+                branch: BitbucketBranchName {
+                    name: request.target_branch,
+                },
+            },
+        };
+
+        let response = self
+            .post(&format!("/repositories/{}/{}/pullrequests", owner, repo))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::ApiStatus(response.status().as_u16()));
+        }
+
+        let raw: BitbucketPullRequest = response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_pull_request())
+    }
+
+    async fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating an issue requires an authenticated Bitbucket client".to_string(),
+            ));
+        }
+
+        let body = BitbucketCreateIssue {
+            title: request.title,
+            content: request.description.map(|raw| BitbucketContent { raw }),
         };
 
-        Ok(repo_info)
+        let response = self
+            .post(&format!("/repositories/{}/{}/issues", owner, repo))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::ApiStatus(response.status().as_u16()));
+        }
+
+        let raw: BitbucketIssue = response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_issue())
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketAccount {
+    display_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketBranchName {
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketBranchRef {
+    branch: BitbucketBranchName,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketHtmlLink {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketLinks {
+    html: BitbucketHtmlLink,
+}
+
+/// Request body for `POST .../pullrequests`.
+#[derive(Debug, Serialize)]
+struct BitbucketCreatePullRequest {
+    title: String,
+    description: Option<String>,
+    source: BitbucketBranchRef,
+    destination: BitbucketBranchRef,
+}
+
+/// Request body for `POST .../issues`.
+#[derive(Debug, Serialize)]
+struct BitbucketCreateIssue {
+    title: String,
+    content: Option<BitbucketContent>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketContent {
+    raw: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketPullRequest {
+    id: u64,
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    state: String,
+    author: BitbucketAccount,
+    created_on: String,
+    updated_on: String,
+    source: BitbucketBranchRef,
+    destination: BitbucketBranchRef,
+    links: BitbucketLinks,
+}
+
+impl BitbucketPullRequest {
+    fn into_pull_request(self) -> PullRequest {
+        let state = match self.state.as_str() {
+            "MERGED" => PullRequestState::Merged,
+            "DECLINED" | "SUPERSEDED" => PullRequestState::Closed,
+            _ => PullRequestState::Open,
+        };
+
+        PullRequest {
+            // Bitbucket's pull request `id` is only unique within a repository, matching
+            // `number` below; there is no separate globally-unique identifier to surface.
+            id: self.id,
+            number: self.id as u32,
+            title: self.title,
+            description: self.description,
+            state,
+            author: self.author.display_name,
+            created_at: parse_rfc3339_timestamp(&self.created_on).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_on).unwrap_or(0),
+            source_branch: self.source.branch.name,
+            target_branch: self.destination.branch.name,
+            url: self.links.html.href,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketIssue {
+    id: u64,
+    title: String,
+    #[serde(default)]
+    content: Option<BitbucketContent>,
+    state: String,
+    #[serde(default)]
+    reporter: Option<BitbucketAccount>,
+    created_on: String,
+    updated_on: String,
+    links: BitbucketLinks,
+}
+
+impl BitbucketIssue {
+    fn into_issue(self) -> Issue {
+        Issue {
+            id: self.id,
+            number: self.id as u32,
+            title: self.title,
+            description: self.content.map(|c| c.raw),
+            state: match self.state.as_str() {
+                "new" | "open" => IssueState::Open,
+                _ => IssueState::Closed,
+            },
+            author: self.reporter.map(|a| a.display_name).unwrap_or_default(),
+            created_at: parse_rfc3339_timestamp(&self.created_on).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_on).unwrap_or(0),
+            url: self.links.html.href,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketMainBranch {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketRepository {
+    name: String,
+    full_name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    website: Option<String>,
+    created_on: String,
+    updated_on: String,
+    mainbranch: Option<BitbucketMainBranch>,
+    links: BitbucketLinks,
+}
+
+impl BitbucketRepository {
+    fn into_repository_info(self) -> RepositoryInfo {
+        // The Bitbucket Cloud API has no equivalent of GitHub/GitLab's star/fork counts
+        // on the repository endpoint, so these are left at 0 rather than faked.
+        let owner = self
+            .full_name
+            .split('/')
+            .next()
+            .unwrap_or(&self.full_name)
+            .to_string();
+
+        RepositoryInfo {
+            name: self.name,
+            owner,
+            description: self.description,
+            stars: 0,
+            forks: 0,
+            created_at: parse_rfc3339_timestamp(&self.created_on).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_on).unwrap_or(0),
+            default_branch: self.mainbranch.map(|b| b.name).unwrap_or_default(),
+            homepage: self.website,
+            url: self.links.html.href,
+        }
+    }
+}
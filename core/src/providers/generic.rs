@@ -2,12 +2,32 @@
 
 use crate::error::GitError;
 use crate::types::Result;
+#[cfg(feature = "async")]
+use crate::providers::AsyncProviderClient;
 use crate::providers::{
-    ProviderOperations, ProviderClient, ProviderType,
+    Auth, CreateIssue, CreatePullRequest, ListOptions, ProviderOperations, ProviderClient, ProviderType,
     PullRequest, PullRequestState, Issue, IssueState, RepositoryInfo,
 };
 
 use std::sync::Arc;
+use regex::Regex;
+
+/// Extracts the last two `/`-separated path segments of a URL as `(owner, repo)`, e.g.
+/// `"https://git.mycorp.com/team/project.git"` -> `("team", "project")`. Used as a
+/// best-effort fallback for providers with no host-specific URL format to rely on.
+fn parse_last_two_path_segments(url: &str) -> Result<(String, String)> {
+    let trimmed = url.trim_end_matches('/');
+    let segments: Vec<&str> = trimmed
+        .trim_end_matches(".git")
+        .split(['/', ':'])
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match segments.len() {
+        0 | 1 => Err(GitError::InvalidUrl(url.to_string())),
+        n => Ok((segments[n - 2].to_string(), segments[n - 1].to_string())),
+    }
+}
 
 /// Generic provider implementation.
 pub struct GenericProvider {
@@ -37,10 +57,26 @@ impl ProviderOperations for GenericProvider {
         true
     }
 
-    fn create_client(&self, auth_token: Option<String>) -> Result<Box<dyn ProviderClient>> {
+    fn parse_owner_repo(&self, url: &str) -> Result<(String, String)> {
+        parse_last_two_path_segments(url)
+    }
+
+    fn create_client(&self, auth: Option<Auth>) -> Result<Box<dyn ProviderClient>> {
         Ok(Box::new(GenericClient {
             provider: Arc::new(self.clone()),
-            auth_token,
+            auth,
+        }))
+    }
+
+    fn clone_box(&self) -> Box<dyn ProviderOperations> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_client(&self, options: crate::providers::ClientOptions) -> Result<Box<dyn AsyncProviderClient>> {
+        Ok(Box::new(GenericAsyncClient {
+            provider: Arc::new(self.clone()),
+            auth: options.auth,
         }))
     }
 }
@@ -56,7 +92,7 @@ impl Clone for GenericProvider {
 /// Generic client implementation.
 pub struct GenericClient {
     provider: Arc<GenericProvider>,
-    auth_token: Option<String>,
+    auth: Option<Auth>,
 }
 
 impl ProviderClient for GenericClient {
@@ -65,17 +101,17 @@ impl ProviderClient for GenericClient {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.auth_token.is_some()
+        self.auth.is_some()
     }
 
-    fn get_pull_requests(&self, _owner: &str, _repo: &str) -> Result<Vec<PullRequest>> {
+    fn get_pull_requests(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         // Generic provider doesn't support pull requests
         Err(GitError::AnalysisError(
             "Pull requests are not supported for generic Git providers".to_string(),
         ))
     }
 
-    fn get_issues(&self, _owner: &str, _repo: &str) -> Result<Vec<Issue>> {
+    fn get_issues(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<Issue>> {
         // Generic provider doesn't support issues
         Err(GitError::AnalysisError(
             "Issues are not supported for generic Git providers".to_string(),
@@ -99,4 +135,299 @@ impl ProviderClient for GenericClient {
 
         Ok(repo_info)
     }
+
+    fn create_pull_request(&self, _owner: &str, _repo: &str, _request: CreatePullRequest) -> Result<PullRequest> {
+        // Generic provider doesn't support pull requests
+        Err(GitError::AnalysisError(
+            "Pull requests are not supported for generic Git providers".to_string(),
+        ))
+    }
+
+    fn create_issue(&self, _owner: &str, _repo: &str, _request: CreateIssue) -> Result<Issue> {
+        // Generic provider doesn't support issues
+        Err(GitError::AnalysisError(
+            "Issues are not supported for generic Git providers".to_string(),
+        ))
+    }
+}
+
+/// Generic client implementation backed by an async HTTP client, for callers integrating
+/// provider lookups into a tokio-based pipeline.
+///
+/// Only available when the `async` feature is enabled.
+#[cfg(feature = "async")]
+pub struct GenericAsyncClient {
+    provider: Arc<GenericProvider>,
+    auth: Option<Auth>,
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncProviderClient for GenericAsyncClient {
+    fn provider_type(&self) -> ProviderType {
+        ProviderType::Generic
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    async fn get_pull_requests(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
+        // Generic provider doesn't support pull requests
+        Err(GitError::AnalysisError(
+            "Pull requests are not supported for generic Git providers".to_string(),
+        ))
+    }
+
+    async fn get_issues(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<Issue>> {
+        // Generic provider doesn't support issues
+        Err(GitError::AnalysisError(
+            "Issues are not supported for generic Git providers".to_string(),
+        ))
+    }
+
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
+        // Generic provider has limited repository info
+        let repo_info = RepositoryInfo {
+            name: repo.to_string(),
+            owner: owner.to_string(),
+            description: None,
+            stars: 0,
+            forks: 0,
+            created_at: 0, // Unknown
+            updated_at: 0, // Unknown
+            default_branch: "main".to_string(), // Assume default
+            homepage: None,
+            url: "".to_string(), // Unknown URL
+        };
+
+        Ok(repo_info)
+    }
+
+    async fn create_pull_request(&self, _owner: &str, _repo: &str, _request: CreatePullRequest) -> Result<PullRequest> {
+        // Generic provider doesn't support pull requests
+        Err(GitError::AnalysisError(
+            "Pull requests are not supported for generic Git providers".to_string(),
+        ))
+    }
+
+    async fn create_issue(&self, _owner: &str, _repo: &str, _request: CreateIssue) -> Result<Issue> {
+        // Generic provider doesn't support issues
+        Err(GitError::AnalysisError(
+            "Issues are not supported for generic Git providers".to_string(),
+        ))
+    }
+}
+
+/// A provider for a self-hosted instance of a known hosting platform (GitHub Enterprise,
+/// Bitbucket Server/Data Center, a self-managed GitLab) whose host doesn't match the public
+/// SaaS URL patterns hard-coded into `GitHubProvider`/`GitLabProvider`/`BitbucketProvider`.
+///
+/// Reports `kind` as its `provider_type`, so callers can still tell which platform family a
+/// matched repository belongs to, while matching URLs against the caller-supplied `host`
+/// instead of a fixed hostname.
+pub struct SelfHostedProvider {
+    kind: ProviderType,
+    api_url: String,
+    url_regex: Regex,
+}
+
+impl SelfHostedProvider {
+    /// Creates a self-hosted provider of `kind` for `host`, using `api_url` as its API base.
+    ///
+    /// # Arguments
+    /// * `kind` - The hosting platform this instance speaks, e.g. `ProviderType::GitHub`.
+    /// * `host` - The web host, e.g. `git.mycorp.com`.
+    /// * `api_url` - The base API URL for this instance.
+    pub fn new(kind: ProviderType, host: &str, api_url: &str) -> Self {
+        let pattern = format!(
+            r"(?i)^(?:https?://(?:www\.)?{0}/|git@{0}:)([^/]+)/([^/]+?)(?:\.git)?/?$",
+            regex::escape(host)
+        );
+
+        SelfHostedProvider {
+            kind,
+            api_url: api_url.to_string(),
+            url_regex: Regex::new(&pattern).expect("Invalid self-hosted provider URL regex"),
+        }
+    }
+
+    /// Extracts owner and repository name from a URL matching this instance's host.
+    pub fn parse_url(&self, url: &str) -> Result<(String, String)> {
+        if let Some(captures) = self.url_regex.captures(url) {
+            let owner = captures.get(1).unwrap().as_str().to_string();
+            let repo = captures.get(2).unwrap().as_str().to_string();
+            Ok((owner, repo))
+        } else {
+            Err(GitError::InvalidUrl(url.to_string()))
+        }
+    }
+}
+
+impl ProviderOperations for SelfHostedProvider {
+    fn provider_type(&self) -> ProviderType {
+        self.kind
+    }
+
+    fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    fn matches_url(&self, url: &str) -> bool {
+        self.url_regex.is_match(url)
+    }
+
+    fn parse_owner_repo(&self, url: &str) -> Result<(String, String)> {
+        self.parse_url(url)
+    }
+
+    fn create_client(&self, auth: Option<Auth>) -> Result<Box<dyn ProviderClient>> {
+        Ok(Box::new(SelfHostedClient {
+            kind: self.kind,
+            auth,
+        }))
+    }
+
+    fn clone_box(&self) -> Box<dyn ProviderOperations> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_client(&self, options: crate::providers::ClientOptions) -> Result<Box<dyn AsyncProviderClient>> {
+        Ok(Box::new(SelfHostedAsyncClient {
+            kind: self.kind,
+            auth: options.auth,
+        }))
+    }
+}
+
+impl Clone for SelfHostedProvider {
+    fn clone(&self) -> Self {
+        SelfHostedProvider {
+            kind: self.kind,
+            api_url: self.api_url.clone(),
+            url_regex: self.url_regex.clone(),
+        }
+    }
+}
+
+/// Client for a `SelfHostedProvider`. Like `GenericClient`, this does not yet call a real
+/// API, since the wire format depends on which platform (`kind`) the instance runs.
+pub struct SelfHostedClient {
+    kind: ProviderType,
+    auth: Option<Auth>,
+}
+
+impl ProviderClient for SelfHostedClient {
+    fn provider_type(&self) -> ProviderType {
+        self.kind
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    fn get_pull_requests(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
+        Err(GitError::AnalysisError(
+            "Pull requests are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+
+    fn get_issues(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<Issue>> {
+        Err(GitError::AnalysisError(
+            "Issues are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+
+    fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
+        let repo_info = RepositoryInfo {
+            name: repo.to_string(),
+            owner: owner.to_string(),
+            description: None,
+            stars: 0,
+            forks: 0,
+            created_at: 0, // Unknown
+            updated_at: 0, // Unknown
+            default_branch: "main".to_string(), // Assume default
+            homepage: None,
+            url: "".to_string(), // Unknown URL
+        };
+
+        Ok(repo_info)
+    }
+
+    fn create_pull_request(&self, _owner: &str, _repo: &str, _request: CreatePullRequest) -> Result<PullRequest> {
+        Err(GitError::AnalysisError(
+            "Pull requests are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+
+    fn create_issue(&self, _owner: &str, _repo: &str, _request: CreateIssue) -> Result<Issue> {
+        Err(GitError::AnalysisError(
+            "Issues are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+}
+
+/// Async client for a `SelfHostedProvider`, for callers integrating provider lookups into a
+/// tokio-based pipeline.
+///
+/// Only available when the `async` feature is enabled.
+#[cfg(feature = "async")]
+pub struct SelfHostedAsyncClient {
+    kind: ProviderType,
+    auth: Option<Auth>,
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncProviderClient for SelfHostedAsyncClient {
+    fn provider_type(&self) -> ProviderType {
+        self.kind
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    async fn get_pull_requests(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
+        Err(GitError::AnalysisError(
+            "Pull requests are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+
+    async fn get_issues(&self, _owner: &str, _repo: &str, _options: Option<ListOptions>) -> Result<Vec<Issue>> {
+        Err(GitError::AnalysisError(
+            "Issues are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
+        let repo_info = RepositoryInfo {
+            name: repo.to_string(),
+            owner: owner.to_string(),
+            description: None,
+            stars: 0,
+            forks: 0,
+            created_at: 0, // Unknown
+            updated_at: 0, // Unknown
+            default_branch: "main".to_string(), // Assume default
+            homepage: None,
+            url: "".to_string(), // Unknown URL
+        };
+
+        Ok(repo_info)
+    }
+
+    async fn create_pull_request(&self, _owner: &str, _repo: &str, _request: CreatePullRequest) -> Result<PullRequest> {
+        Err(GitError::AnalysisError(
+            "Pull requests are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
+
+    async fn create_issue(&self, _owner: &str, _repo: &str, _request: CreateIssue) -> Result<Issue> {
+        Err(GitError::AnalysisError(
+            "Issues are not yet supported for self-hosted providers".to_string(),
+        ))
+    }
 }
\ No newline at end of file
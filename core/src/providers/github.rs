@@ -2,15 +2,19 @@
 
 use crate::error::GitError;
 use crate::types::Result;
+#[cfg(feature = "async")]
+use crate::providers::AsyncProviderClient;
 use crate::providers::{
+    parse_rfc3339_timestamp,
+    Auth, ClientOptions, CreateIssue, CreatePullRequest, ListOptions,
     ProviderOperations, ProviderClient, ProviderType,
     PullRequest, PullRequestState, Issue, IssueState, RepositoryInfo,
 };
 
 use std::sync::Arc;
-use std::collections::HashMap;
 use regex::Regex;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 
 // Regular expression to match GitHub URLs
 static GITHUB_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -21,19 +25,38 @@ static GITHUB_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
 /// GitHub provider implementation.
 pub struct GitHubProvider {
     api_url: String,
+    url_regex: Regex,
 }
 
 impl GitHubProvider {
-    /// Creates a new GitHub provider.
+    /// Creates a new GitHub provider for the public github.com host.
     pub fn new() -> Self {
         GitHubProvider {
             api_url: "https://api.github.com".to_string(),
+            url_regex: GITHUB_URL_REGEX.clone(),
+        }
+    }
+
+    /// Creates a GitHub provider for a self-hosted GitHub Enterprise instance.
+    ///
+    /// # Arguments
+    /// * `host` - The web host, e.g. `github.mycorp.com`.
+    /// * `api_url` - The base API URL, e.g. `https://github.mycorp.com/api/v3`.
+    pub fn with_host(host: &str, api_url: &str) -> Self {
+        let pattern = format!(
+            r"(?i)^(?:https?://(?:www\.)?{0}/|git@{0}:)([^/]+)/([^/]+?)(?:\.git)?/?$",
+            regex::escape(host)
+        );
+
+        GitHubProvider {
+            api_url: api_url.to_string(),
+            url_regex: Regex::new(&pattern).expect("Invalid GitHub URL regex"),
         }
     }
 
     /// Extracts owner and repository name from a GitHub URL.
     pub fn parse_url(&self, url: &str) -> Result<(String, String)> {
-        if let Some(captures) = GITHUB_URL_REGEX.captures(url) {
+        if let Some(captures) = self.url_regex.captures(url) {
             let owner = captures.get(1).unwrap().as_str().to_string();
             let repo = captures.get(2).unwrap().as_str().to_string();
             Ok((owner, repo))
@@ -53,13 +76,56 @@ impl ProviderOperations for GitHubProvider {
     }
 
     fn matches_url(&self, url: &str) -> bool {
-        GITHUB_URL_REGEX.is_match(url)
+        self.url_regex.is_match(url)
+    }
+
+    fn parse_owner_repo(&self, url: &str) -> Result<(String, String)> {
+        self.parse_url(url)
+    }
+
+    fn create_client(&self, auth: Option<Auth>) -> Result<Box<dyn ProviderClient>> {
+        self.create_client_with_options(ClientOptions {
+            auth,
+            ..Default::default()
+        })
     }
 
-    fn create_client(&self, auth_token: Option<String>) -> Result<Box<dyn ProviderClient>> {
+    fn create_client_with_options(&self, options: ClientOptions) -> Result<Box<dyn ProviderClient>> {
+        let mut builder = reqwest::blocking::Client::builder();
+
+        if let Some(pem) = &options.root_certificate_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| GitError::Http(e.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http_client = builder.build().map_err(|e| GitError::Http(e.to_string()))?;
+
         Ok(Box::new(GitHubClient {
             provider: Arc::new(self.clone()),
-            auth_token,
+            auth: options.auth,
+            http_client,
+        }))
+    }
+
+    fn clone_box(&self) -> Box<dyn ProviderOperations> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_client(&self, options: ClientOptions) -> Result<Box<dyn AsyncProviderClient>> {
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(pem) = &options.root_certificate_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| GitError::Http(e.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http_client = builder.build().map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(Box::new(GitHubAsyncClient {
+            provider: Arc::new(self.clone()),
+            auth: options.auth,
+            http_client,
         }))
     }
 }
@@ -68,6 +134,7 @@ impl Clone for GitHubProvider {
     fn clone(&self) -> Self {
         GitHubProvider {
             api_url: self.api_url.clone(),
+            url_regex: self.url_regex.clone(),
         }
     }
 }
@@ -75,7 +142,81 @@ impl Clone for GitHubProvider {
 /// GitHub client implementation.
 pub struct GitHubClient {
     provider: Arc<GitHubProvider>,
-    auth_token: Option<String>,
+    auth: Option<Auth>,
+    http_client: reqwest::blocking::Client,
+}
+
+impl GitHubClient {
+    /// Builds a GET request against the GitHub API, attaching credentials in whichever
+    /// form `self.auth` holds.
+    fn get(&self, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self
+            .http_client
+            .get(&url)
+            .header("User-Agent", "gitlens")
+            .header("Accept", "application/vnd.github+json");
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) | Some(Auth::CiJobToken(token)) => {
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+
+    /// Builds a POST request against the GitHub API, attaching credentials the same way
+    /// as `get`.
+    fn post(&self, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self
+            .http_client
+            .post(&url)
+            .header("User-Agent", "gitlens")
+            .header("Accept", "application/vnd.github+json");
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) | Some(Auth::CiJobToken(token)) => {
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+}
+
+/// Reads the number of the last page from a GitHub `Link` response header, e.g.
+/// `<https://api.github.com/...&page=4>; rel="last"`. Returns 1 when there's no `Link`
+/// header at all (a single page of results).
+fn parse_github_last_page(headers: &reqwest::header::HeaderMap) -> usize {
+    let Some(link) = headers.get(reqwest::header::LINK).and_then(|v| v.to_str().ok()) else {
+        return 1;
+    };
+
+    for part in link.split(',') {
+        if !part.contains("rel=\"last\"") {
+            continue;
+        }
+        if let Some(page) = part
+            .split(|c: char| c == '?' || c == '&')
+            .find_map(|param| param.strip_prefix("page="))
+            .and_then(|rest| rest.split(|c: char| !c.is_ascii_digit()).next())
+            .and_then(|digits| digits.parse().ok())
+        {
+            return page;
+        }
+    }
+
+    1
 }
 
 impl ProviderClient for GitHubClient {
@@ -84,90 +225,595 @@ impl ProviderClient for GitHubClient {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.auth_token.is_some()
+        self.auth.is_some()
     }
 
-    fn get_pull_requests(&self, owner: &str, repo: &str) -> Result<Vec<PullRequest>> {
-        // To implement this properly, use an HTTP client to call the GitHub API
-        // For now, return a placeholder with error if not authenticated
+    fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for GitHub API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call GitHub API
-        let prs = vec![
-            // Example PRs for testing
-            PullRequest {
-                id: 1,
-                number: 1,
-                title: "Example pull request".to_string(),
-                description: Some("This is an example PR description".to_string()),
-                state: PullRequestState::Open,
-                author: "example-user".to_string(),
-                created_at: 1617235200, // Example timestamp
-                updated_at: 1617235200,
-                source_branch: "feature-branch".to_string(),
-                target_branch: "main".to_string(),
-                url: format!("https://github.com/{}/{}/pull/1", owner, repo),
-            },
-        ];
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("open");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/repos/{}/{}/pulls", owner, repo);
+
+        let fetch_page = |page: usize| -> Result<(Vec<GitHubPullRequest>, usize)> {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitHub API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let last_page = parse_github_last_page(response.headers());
+            let items: Vec<GitHubPullRequest> =
+                response.json().map_err(|e| GitError::Http(e.to_string()))?;
+
+            Ok((items, last_page))
+        };
 
-        Ok(prs)
+        let (first_page, total_pages) = fetch_page(1)?;
+        let raw = crate::providers::paginate(first_page, total_pages, |page| {
+            fetch_page(page).map(|(items, _)| items)
+        })?;
+
+        Ok(raw.into_iter().map(GitHubPullRequest::into_pull_request).collect())
     }
 
-    fn get_issues(&self, owner: &str, repo: &str) -> Result<Vec<Issue>> {
-        // To implement this properly, use an HTTP client to call the GitHub API
-        // For now, return a placeholder with error if not authenticated
+    fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for GitHub API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call GitHub API
-        let issues = vec![
-            // Example issues for testing
-            Issue {
-                id: 1,
-                number: 1,
-                title: "Example issue".to_string(),
-                description: Some("This is an example issue description".to_string()),
-                state: IssueState::Open,
-                author: "example-user".to_string(),
-                created_at: 1617235200, // Example timestamp
-                updated_at: 1617235200,
-                url: format!("https://github.com/{}/{}/issues/1", owner, repo),
-            },
-        ];
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("open");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/repos/{}/{}/issues", owner, repo);
+
+        let fetch_page = |page: usize| -> Result<(Vec<GitHubIssue>, usize)> {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitHub API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let last_page = parse_github_last_page(response.headers());
+            let items: Vec<GitHubIssue> =
+                response.json().map_err(|e| GitError::Http(e.to_string()))?;
+
+            Ok((items, last_page))
+        };
+
+        let (first_page, total_pages) = fetch_page(1)?;
+        let raw = crate::providers::paginate(first_page, total_pages, |page| {
+            fetch_page(page).map(|(items, _)| items)
+        })?;
 
-        Ok(issues)
+        Ok(raw
+            .into_iter()
+            // The GitHub issues endpoint also returns pull requests; those are
+            // already surfaced by `get_pull_requests`, so filter them out here.
+            .filter(|issue| issue.pull_request.is_none())
+            .map(GitHubIssue::into_issue)
+            .collect())
     }
 
     fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
-        // To implement this properly, use an HTTP client to call the GitHub API
-        // For now, return a placeholder with error if not authenticated
+        // Repository metadata is a public-read endpoint; GitHub allows anonymous
+        // (unauthenticated) access for public repositories.
+        let response = self
+            .get(&format!("/repos/{}/{}", owner, repo))
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitHub API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitHubRepository = response
+            .json()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_repository_info())
+    }
+
+    fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating a pull request requires an authenticated GitHub client".to_string(),
+            ));
+        }
+
+        let body = GitHubCreatePullRequest {
+            title: request.title,
+            body: request.description,
+            head: request.source_branch,
+            base: request.target_branch,
+        };
+
+        let response = self
+            .post(&format!("/repos/{}/{}/pulls", owner, repo))
+            .json(&body)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitHub API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitHubPullRequest = response
+            .json()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_pull_request())
+    }
+
+    fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating an issue requires an authenticated GitHub client".to_string(),
+            ));
+        }
+
+        let body = GitHubCreateIssue {
+            title: request.title,
+            body: request.description,
+        };
+
+        let response = self
+            .post(&format!("/repos/{}/{}/issues", owner, repo))
+            .json(&body)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitHub API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitHubIssue = response
+            .json()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_issue())
+    }
+}
+
+/// GitHub client implementation backed by an async HTTP client, for callers integrating
+/// provider lookups into a tokio-based pipeline.
+///
+/// Only available when the `async` feature is enabled.
+#[cfg(feature = "async")]
+pub struct GitHubAsyncClient {
+    provider: Arc<GitHubProvider>,
+    auth: Option<Auth>,
+    http_client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl GitHubAsyncClient {
+    /// Builds a GET request against the GitHub API, attaching credentials in whichever
+    /// form `self.auth` holds.
+    fn get(&self, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self
+            .http_client
+            .get(&url)
+            .header("User-Agent", "gitlens")
+            .header("Accept", "application/vnd.github+json");
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) | Some(Auth::CiJobToken(token)) => {
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+
+    /// Builds a POST request against the GitHub API, attaching credentials the same way
+    /// as `get`.
+    fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self
+            .http_client
+            .post(&url)
+            .header("User-Agent", "gitlens")
+            .header("Accept", "application/vnd.github+json");
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) | Some(Auth::CiJobToken(token)) => {
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncProviderClient for GitHubAsyncClient {
+    fn provider_type(&self) -> ProviderType {
+        ProviderType::GitHub
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    async fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for GitHub API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call GitHub API
-        let repo_info = RepositoryInfo {
-            name: repo.to_string(),
-            owner: owner.to_string(),
-            description: Some("Repository description".to_string()),
-            stars: 0,
-            forks: 0,
-            created_at: 1617235200, // Example timestamp
-            updated_at: 1617235200,
-            default_branch: "main".to_string(),
-            homepage: None,
-            url: format!("https://github.com/{}/{}", owner, repo),
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("open");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/repos/{}/{}/pulls", owner, repo);
+
+        let mut page = 1;
+        let mut all = Vec::new();
+        loop {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitHub API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let last_page = parse_github_last_page(response.headers());
+            let items: Vec<GitHubPullRequest> =
+                response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+            let exhausted = items.is_empty();
+            all.extend(items);
+
+            if page >= last_page || exhausted {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all.into_iter().map(GitHubPullRequest::into_pull_request).collect())
+    }
+
+    async fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>> {
+        if !self.is_authenticated() {
+            return Err(GitError::AnalysisError(
+                "Authentication required for GitHub API".to_string(),
+            ));
+        }
+
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("open");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/repos/{}/{}/issues", owner, repo);
+
+        let mut page = 1;
+        let mut all = Vec::new();
+        loop {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitHub API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let last_page = parse_github_last_page(response.headers());
+            let items: Vec<GitHubIssue> =
+                response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+            let exhausted = items.is_empty();
+            all.extend(items);
+
+            if page >= last_page || exhausted {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all
+            .into_iter()
+            // The GitHub issues endpoint also returns pull requests; those are
+            // already surfaced by `get_pull_requests`, so filter them out here.
+            .filter(|issue| issue.pull_request.is_none())
+            .map(GitHubIssue::into_issue)
+            .collect())
+    }
+
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
+        // Repository metadata is a public-read endpoint; GitHub allows anonymous
+        // (unauthenticated) access for public repositories.
+        let response = self
+            .get(&format!("/repos/{}/{}", owner, repo))
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitHub API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitHubRepository = response
+            .json()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_repository_info())
+    }
+
+    async fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating a pull request requires an authenticated GitHub client".to_string(),
+            ));
+        }
+
+        let body = GitHubCreatePullRequest {
+            title: request.title,
+            body: request.description,
+            head: request.source_branch,
+            base: request.target_branch,
         };
 
-        Ok(repo_info)
+        let response = self
+            .post(&format!("/repos/{}/{}/pulls", owner, repo))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitHub API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitHubPullRequest = response
+            .json()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_pull_request())
+    }
+
+    async fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating an issue requires an authenticated GitHub client".to_string(),
+            ));
+        }
+
+        let body = GitHubCreateIssue {
+            title: request.title,
+            body: request.description,
+        };
+
+        let response = self
+            .post(&format!("/repos/{}/{}/issues", owner, repo))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitHub API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitHubIssue = response
+            .json()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_issue())
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRef {
+    #[serde(rename = "ref")]
+    ref_name: String,
+}
+
+/// Request body for `POST /repos/{owner}/{repo}/pulls`.
+#[derive(Debug, Serialize)]
+struct GitHubCreatePullRequest {
+    title: String,
+    body: Option<String>,
+    head: String,
+    base: String,
+}
+
+/// Request body for `POST /repos/{owner}/{repo}/issues`.
+#[derive(Debug, Serialize)]
+struct GitHubCreateIssue {
+    title: String,
+    body: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubPullRequest {
+    id: u64,
+    number: u32,
+    title: String,
+    body: Option<String>,
+    state: String,
+    user: GitHubUser,
+    created_at: String,
+    updated_at: String,
+    merged_at: Option<String>,
+    head: GitHubRef,
+    base: GitHubRef,
+    html_url: String,
+}
+
+impl GitHubPullRequest {
+    fn into_pull_request(self) -> PullRequest {
+        let state = if self.merged_at.is_some() {
+            PullRequestState::Merged
+        } else if self.state == "closed" {
+            PullRequestState::Closed
+        } else {
+            PullRequestState::Open
+        };
+
+        PullRequest {
+            id: self.id,
+            number: self.number,
+            title: self.title,
+            description: self.body,
+            state,
+            author: self.user.login,
+            created_at: parse_rfc3339_timestamp(&self.created_at).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_at).unwrap_or(0),
+            source_branch: self.head.ref_name,
+            target_branch: self.base.ref_name,
+            url: self.html_url,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubIssue {
+    id: u64,
+    number: u32,
+    title: String,
+    body: Option<String>,
+    state: String,
+    user: GitHubUser,
+    created_at: String,
+    updated_at: String,
+    html_url: String,
+    /// Present (non-null) only when this "issue" is actually a pull request.
+    pull_request: Option<serde::de::IgnoredAny>,
+}
+
+impl GitHubIssue {
+    fn into_issue(self) -> Issue {
+        Issue {
+            id: self.id,
+            number: self.number,
+            title: self.title,
+            description: self.body,
+            state: if self.state == "closed" {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            author: self.user.login,
+            created_at: parse_rfc3339_timestamp(&self.created_at).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_at).unwrap_or(0),
+            url: self.html_url,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepository {
+    name: String,
+    owner: GitHubUser,
+    description: Option<String>,
+    stargazers_count: u32,
+    forks_count: u32,
+    created_at: String,
+    updated_at: String,
+    default_branch: String,
+    homepage: Option<String>,
+    html_url: String,
+}
+
+impl GitHubRepository {
+    fn into_repository_info(self) -> RepositoryInfo {
+        RepositoryInfo {
+            name: self.name,
+            owner: self.owner.login,
+            description: self.description,
+            stars: self.stargazers_count,
+            forks: self.forks_count,
+            created_at: parse_rfc3339_timestamp(&self.created_at).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_at).unwrap_or(0),
+            default_branch: self.default_branch,
+            homepage: self.homepage,
+            url: self.html_url,
+        }
+    }
+}
@@ -2,15 +2,19 @@
 
 use crate::error::GitError;
 use crate::types::Result;
+#[cfg(feature = "async")]
+use crate::providers::AsyncProviderClient;
 use crate::providers::{
+    parse_rfc3339_timestamp,
+    Auth, ClientOptions, CreateIssue, CreatePullRequest, ListOptions,
     ProviderOperations, ProviderClient, ProviderType,
     PullRequest, PullRequestState, Issue, IssueState, RepositoryInfo,
 };
 
 use std::sync::Arc;
-use std::collections::HashMap;
 use regex::Regex;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 
 // Regular expression to match GitLab URLs
 static GITLAB_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -21,19 +25,38 @@ static GITLAB_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
 /// GitLab provider implementation.
 pub struct GitLabProvider {
     api_url: String,
+    url_regex: Regex,
 }
 
 impl GitLabProvider {
-    /// Creates a new GitLab provider.
+    /// Creates a new GitLab provider for the public gitlab.com host.
     pub fn new() -> Self {
         GitLabProvider {
             api_url: "https://gitlab.com/api/v4".to_string(),
+            url_regex: GITLAB_URL_REGEX.clone(),
+        }
+    }
+
+    /// Creates a GitLab provider for a self-managed GitLab instance.
+    ///
+    /// # Arguments
+    /// * `host` - The web host, e.g. `gitlab.mycorp.com`.
+    /// * `api_url` - The base API URL, e.g. `https://gitlab.mycorp.com/api/v4`.
+    pub fn with_host(host: &str, api_url: &str) -> Self {
+        let pattern = format!(
+            r"(?i)^(?:https?://(?:www\.)?{0}/|git@{0}:)([^/]+)/([^/]+?)(?:\.git)?/?$",
+            regex::escape(host)
+        );
+
+        GitLabProvider {
+            api_url: api_url.to_string(),
+            url_regex: Regex::new(&pattern).expect("Invalid GitLab URL regex"),
         }
     }
 
     /// Extracts owner and repository name from a GitLab URL.
     pub fn parse_url(&self, url: &str) -> Result<(String, String)> {
-        if let Some(captures) = GITLAB_URL_REGEX.captures(url) {
+        if let Some(captures) = self.url_regex.captures(url) {
             let owner = captures.get(1).unwrap().as_str().to_string();
             let repo = captures.get(2).unwrap().as_str().to_string();
             Ok((owner, repo))
@@ -53,13 +76,56 @@ impl ProviderOperations for GitLabProvider {
     }
 
     fn matches_url(&self, url: &str) -> bool {
-        GITLAB_URL_REGEX.is_match(url)
+        self.url_regex.is_match(url)
+    }
+
+    fn parse_owner_repo(&self, url: &str) -> Result<(String, String)> {
+        self.parse_url(url)
+    }
+
+    fn create_client(&self, auth: Option<Auth>) -> Result<Box<dyn ProviderClient>> {
+        self.create_client_with_options(ClientOptions {
+            auth,
+            ..Default::default()
+        })
     }
 
-    fn create_client(&self, auth_token: Option<String>) -> Result<Box<dyn ProviderClient>> {
+    fn create_client_with_options(&self, options: ClientOptions) -> Result<Box<dyn ProviderClient>> {
+        let mut builder = reqwest::blocking::Client::builder();
+
+        if let Some(pem) = &options.root_certificate_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| GitError::Http(e.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http_client = builder.build().map_err(|e| GitError::Http(e.to_string()))?;
+
         Ok(Box::new(GitLabClient {
             provider: Arc::new(self.clone()),
-            auth_token,
+            auth: options.auth,
+            http_client,
+        }))
+    }
+
+    fn clone_box(&self) -> Box<dyn ProviderOperations> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_client(&self, options: ClientOptions) -> Result<Box<dyn AsyncProviderClient>> {
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(pem) = &options.root_certificate_pem {
+            let cert = reqwest::Certificate::from_pem(pem).map_err(|e| GitError::Http(e.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let http_client = builder.build().map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(Box::new(GitLabAsyncClient {
+            provider: Arc::new(self.clone()),
+            auth: options.auth,
+            http_client,
         }))
     }
 }
@@ -68,6 +134,7 @@ impl Clone for GitLabProvider {
     fn clone(&self) -> Self {
         GitLabProvider {
             api_url: self.api_url.clone(),
+            url_regex: self.url_regex.clone(),
         }
     }
 }
@@ -75,7 +142,80 @@ impl Clone for GitLabProvider {
 /// GitLab client implementation.
 pub struct GitLabClient {
     provider: Arc<GitLabProvider>,
-    auth_token: Option<String>,
+    auth: Option<Auth>,
+    http_client: reqwest::blocking::Client,
+}
+
+impl GitLabClient {
+    /// Builds a GET request against the GitLab API, attaching credentials in whichever
+    /// form `self.auth` holds.
+    fn get(&self, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self.http_client.get(&url);
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) => request.header("PRIVATE-TOKEN", token),
+            Some(Auth::CiJobToken(token)) => request.header("JOB-TOKEN", token),
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+
+    /// Builds a POST request against the GitLab API, attaching credentials the same way
+    /// as `get`.
+    fn post(&self, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self.http_client.post(&url);
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) => request.header("PRIVATE-TOKEN", token),
+            Some(Auth::CiJobToken(token)) => request.header("JOB-TOKEN", token),
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+
+    /// GitLab addresses a project by its URL-encoded `owner/repo` path as the `:id`.
+    fn project_id(owner: &str, repo: &str) -> String {
+        format!(
+            "{}%2F{}",
+            percent_encode_path_segment(owner),
+            percent_encode_path_segment(repo)
+        )
+    }
+}
+
+/// Reads the `X-Total-Pages` response header GitLab attaches to paginated list
+/// endpoints. Returns 1 when the header is absent or unparsable (a single page of
+/// results).
+fn parse_gitlab_total_pages(headers: &reqwest::header::HeaderMap) -> usize {
+    headers
+        .get("x-total-pages")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1)
+}
+
+/// Percent-encodes a single path segment, leaving unreserved characters untouched.
+fn percent_encode_path_segment(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for b in input.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
 }
 
 impl ProviderClient for GitLabClient {
@@ -84,91 +224,550 @@ impl ProviderClient for GitLabClient {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.auth_token.is_some()
+        self.auth.is_some()
     }
 
-    fn get_pull_requests(&self, owner: &str, repo: &str) -> Result<Vec<PullRequest>> {
-        // To implement this properly, use an HTTP client to call the GitLab API
-        // For now, return a placeholder with error if not authenticated
+    fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for GitLab API".to_string(),
             ));
         }
 
-        // In GitLab, pull requests are called "merge requests"
-        // Placeholder - in a real implementation, would call GitLab API
-        let prs = vec![
-            // Example MRs for testing
-            PullRequest {
-                id: 1,
-                number: 1,
-                title: "Example merge request".to_string(),
-                description: Some("This is an example MR description".to_string()),
-                state: PullRequestState::Open,
-                author: "example-user".to_string(),
-                created_at: 1617235200, // Example timestamp
-                updated_at: 1617235200,
-                source_branch: "feature-branch".to_string(),
-                target_branch: "main".to_string(),
-                url: format!("https://gitlab.com/{}/{}/merge_requests/1", owner, repo),
-            },
-        ];
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("opened");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/projects/{}/merge_requests", Self::project_id(owner, repo));
+
+        let fetch_page = |page: usize| -> Result<(Vec<GitLabMergeRequest>, usize)> {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitLab API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let total_pages = parse_gitlab_total_pages(response.headers());
+            let items: Vec<GitLabMergeRequest> =
+                response.json().map_err(|e| GitError::Http(e.to_string()))?;
 
-        Ok(prs)
+            Ok((items, total_pages))
+        };
+
+        let (first_page, total_pages) = fetch_page(1)?;
+        let raw = crate::providers::paginate(first_page, total_pages, |page| {
+            fetch_page(page).map(|(items, _)| items)
+        })?;
+
+        Ok(raw.into_iter().map(GitLabMergeRequest::into_pull_request).collect())
     }
 
-    fn get_issues(&self, owner: &str, repo: &str) -> Result<Vec<Issue>> {
-        // To implement this properly, use an HTTP client to call the GitLab API
-        // For now, return a placeholder with error if not authenticated
+    fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for GitLab API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call GitLab API
-        let issues = vec![
-            // Example issues for testing
-            Issue {
-                id: 1,
-                number: 1,
-                title: "Example issue".to_string(),
-                description: Some("This is an example issue description".to_string()),
-                state: IssueState::Open,
-                author: "example-user".to_string(),
-                created_at: 1617235200, // Example timestamp
-                updated_at: 1617235200,
-                url: format!("https://gitlab.com/{}/{}/issues/1", owner, repo),
-            },
-        ];
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("opened");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/projects/{}/issues", Self::project_id(owner, repo));
+
+        let fetch_page = |page: usize| -> Result<(Vec<GitLabIssue>, usize)> {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitLab API returned status {}",
+                    response.status()
+                )));
+            }
 
-        Ok(issues)
+            let total_pages = parse_gitlab_total_pages(response.headers());
+            let items: Vec<GitLabIssue> =
+                response.json().map_err(|e| GitError::Http(e.to_string()))?;
+
+            Ok((items, total_pages))
+        };
+
+        let (first_page, total_pages) = fetch_page(1)?;
+        let raw = crate::providers::paginate(first_page, total_pages, |page| {
+            fetch_page(page).map(|(items, _)| items)
+        })?;
+
+        Ok(raw.into_iter().map(GitLabIssue::into_issue).collect())
     }
 
     fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
-        // To implement this properly, use an HTTP client to call the GitLab API
-        // For now, return a placeholder with error if not authenticated
+        // Project metadata is a public-read endpoint; GitLab allows anonymous
+        // (unauthenticated) access for public projects.
+        let path = format!("/projects/{}", Self::project_id(owner, repo));
+        let response = self
+            .get(&path)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitLab API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitLabProject = response
+            .json()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_repository_info())
+    }
+
+    fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating a merge request requires an authenticated GitLab client".to_string(),
+            ));
+        }
+
+        let body = GitLabCreateMergeRequest {
+            title: request.title,
+            description: request.description,
+            source_branch: request.source_branch,
+            target_branch: request.target_branch,
+        };
+        let response = self
+            .post(&format!("/projects/{}/merge_requests", Self::project_id(owner, repo)))
+            .json(&body)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitLab API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitLabMergeRequest = response.json().map_err(|e| GitError::Http(e.to_string()))?;
+        Ok(raw.into_pull_request())
+    }
+
+    fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating an issue requires an authenticated GitLab client".to_string(),
+            ));
+        }
+
+        let body = GitLabCreateIssue {
+            title: request.title,
+            description: request.description,
+        };
+        let response = self
+            .post(&format!("/projects/{}/issues", Self::project_id(owner, repo)))
+            .json(&body)
+            .send()
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitLab API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitLabIssue = response.json().map_err(|e| GitError::Http(e.to_string()))?;
+        Ok(raw.into_issue())
+    }
+}
+
+/// GitLab client implementation backed by an async HTTP client, for callers integrating
+/// provider lookups into a tokio-based pipeline.
+///
+/// Only available when the `async` feature is enabled.
+#[cfg(feature = "async")]
+pub struct GitLabAsyncClient {
+    provider: Arc<GitLabProvider>,
+    auth: Option<Auth>,
+    http_client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl GitLabAsyncClient {
+    /// Builds a GET request against the GitLab API, attaching credentials in whichever
+    /// form `self.auth` holds.
+    fn get(&self, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self.http_client.get(&url);
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) => request.header("PRIVATE-TOKEN", token),
+            Some(Auth::CiJobToken(token)) => request.header("JOB-TOKEN", token),
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+
+    /// Builds a POST request against the GitLab API, attaching credentials the same way
+    /// as `get`.
+    fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.provider.api_url(), path);
+        let mut request = self.http_client.post(&url);
+
+        request = match &self.auth {
+            Some(Auth::Token(token)) => request.header("PRIVATE-TOKEN", token),
+            Some(Auth::CiJobToken(token)) => request.header("JOB-TOKEN", token),
+            Some(Auth::UsernamePassword(username, password)) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        request
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncProviderClient for GitLabAsyncClient {
+    fn provider_type(&self) -> ProviderType {
+        ProviderType::GitLab
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    async fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>> {
         if !self.is_authenticated() {
             return Err(GitError::AnalysisError(
                 "Authentication required for GitLab API".to_string(),
             ));
         }
 
-        // Placeholder - in a real implementation, would call GitLab API
-        let repo_info = RepositoryInfo {
-            name: repo.to_string(),
-            owner: owner.to_string(),
-            description: Some("Repository description".to_string()),
-            stars: 0,
-            forks: 0,
-            created_at: 1617235200, // Example timestamp
-            updated_at: 1617235200,
-            default_branch: "main".to_string(),
-            homepage: None,
-            url: format!("https://gitlab.com/{}/{}", owner, repo),
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("opened");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/projects/{}/merge_requests", GitLabClient::project_id(owner, repo));
+
+        let mut page = 1;
+        let mut all = Vec::new();
+        loop {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitLab API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let total_pages = parse_gitlab_total_pages(response.headers());
+            let items: Vec<GitLabMergeRequest> =
+                response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+            let exhausted = items.is_empty();
+            all.extend(items);
+
+            if page >= total_pages || exhausted {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all.into_iter().map(GitLabMergeRequest::into_pull_request).collect())
+    }
+
+    async fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>> {
+        if !self.is_authenticated() {
+            return Err(GitError::AnalysisError(
+                "Authentication required for GitLab API".to_string(),
+            ));
+        }
+
+        let options = options.unwrap_or_default();
+        let state = options.state.as_deref().unwrap_or("opened");
+        let per_page = options.per_page.unwrap_or(100);
+        let path = format!("/projects/{}/issues", GitLabClient::project_id(owner, repo));
+
+        let mut page = 1;
+        let mut all = Vec::new();
+        loop {
+            let response = self
+                .get(&path)
+                .query(&[
+                    ("state", state),
+                    ("per_page", &per_page.to_string()),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await
+                .map_err(|e| GitError::Http(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(GitError::Http(format!(
+                    "GitLab API returned status {}",
+                    response.status()
+                )));
+            }
+
+            let total_pages = parse_gitlab_total_pages(response.headers());
+            let items: Vec<GitLabIssue> =
+                response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+
+            let exhausted = items.is_empty();
+            all.extend(items);
+
+            if page >= total_pages || exhausted {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all.into_iter().map(GitLabIssue::into_issue).collect())
+    }
+
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo> {
+        // Project metadata is a public-read endpoint; GitLab allows anonymous
+        // (unauthenticated) access for public projects.
+        let path = format!("/projects/{}", GitLabClient::project_id(owner, repo));
+        let response = self
+            .get(&path)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitLab API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitLabProject = response
+            .json()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        Ok(raw.into_repository_info())
+    }
+
+    async fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating a merge request requires an authenticated GitLab client".to_string(),
+            ));
+        }
+
+        let body = GitLabCreateMergeRequest {
+            title: request.title,
+            description: request.description,
+            source_branch: request.source_branch,
+            target_branch: request.target_branch,
         };
+        let response = self
+            .post(&format!("/projects/{}/merge_requests", GitLabClient::project_id(owner, repo)))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitLab API returned status {}",
+                response.status()
+            )));
+        }
 
-        Ok(repo_info)
+        let raw: GitLabMergeRequest = response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+        Ok(raw.into_pull_request())
     }
-}
\ No newline at end of file
+
+    async fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue> {
+        if !self.is_authenticated() {
+            return Err(GitError::WriteOperationNotPermitted(
+                "Creating an issue requires an authenticated GitLab client".to_string(),
+            ));
+        }
+
+        let body = GitLabCreateIssue {
+            title: request.title,
+            description: request.description,
+        };
+        let response = self
+            .post(&format!("/projects/{}/issues", GitLabClient::project_id(owner, repo)))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GitError::Http(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(GitError::Http(format!(
+                "GitLab API returned status {}",
+                response.status()
+            )));
+        }
+
+        let raw: GitLabIssue = response.json().await.map_err(|e| GitError::Http(e.to_string()))?;
+        Ok(raw.into_issue())
+    }
+}
+
+/// Request body for `POST /projects/:id/merge_requests`.
+#[derive(Debug, Serialize)]
+struct GitLabCreateMergeRequest {
+    title: String,
+    description: Option<String>,
+    source_branch: String,
+    target_branch: String,
+}
+
+/// Request body for `POST /projects/:id/issues`.
+#[derive(Debug, Serialize)]
+struct GitLabCreateIssue {
+    title: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabUser {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabMergeRequest {
+    id: u64,
+    iid: u32,
+    title: String,
+    description: Option<String>,
+    state: String,
+    author: GitLabUser,
+    created_at: String,
+    updated_at: String,
+    source_branch: String,
+    target_branch: String,
+    web_url: String,
+}
+
+impl GitLabMergeRequest {
+    fn into_pull_request(self) -> PullRequest {
+        let state = match self.state.as_str() {
+            "merged" => PullRequestState::Merged,
+            "opened" => PullRequestState::Open,
+            _ => PullRequestState::Closed,
+        };
+
+        PullRequest {
+            id: self.id,
+            number: self.iid,
+            title: self.title,
+            description: self.description,
+            state,
+            author: self.author.username,
+            created_at: parse_rfc3339_timestamp(&self.created_at).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_at).unwrap_or(0),
+            source_branch: self.source_branch,
+            target_branch: self.target_branch,
+            url: self.web_url,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabIssue {
+    id: u64,
+    iid: u32,
+    title: String,
+    description: Option<String>,
+    state: String,
+    author: GitLabUser,
+    created_at: String,
+    updated_at: String,
+    web_url: String,
+}
+
+impl GitLabIssue {
+    fn into_issue(self) -> Issue {
+        Issue {
+            id: self.id,
+            number: self.iid,
+            title: self.title,
+            description: self.description,
+            state: if self.state == "opened" {
+                IssueState::Open
+            } else {
+                IssueState::Closed
+            },
+            author: self.author.username,
+            created_at: parse_rfc3339_timestamp(&self.created_at).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.updated_at).unwrap_or(0),
+            url: self.web_url,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabNamespace {
+    path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabProject {
+    name: String,
+    namespace: GitLabNamespace,
+    description: Option<String>,
+    star_count: u32,
+    forks_count: u32,
+    created_at: String,
+    last_activity_at: String,
+    default_branch: Option<String>,
+    web_url: String,
+}
+
+impl GitLabProject {
+    fn into_repository_info(self) -> RepositoryInfo {
+        RepositoryInfo {
+            name: self.name,
+            owner: self.namespace.path,
+            description: self.description,
+            stars: self.star_count,
+            forks: self.forks_count,
+            created_at: parse_rfc3339_timestamp(&self.created_at).unwrap_or(0),
+            updated_at: parse_rfc3339_timestamp(&self.last_activity_at).unwrap_or(0),
+            default_branch: self.default_branch.unwrap_or_else(|| "main".to_string()),
+            homepage: None,
+            url: self.web_url,
+        }
+    }
+}
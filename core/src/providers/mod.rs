@@ -12,6 +12,7 @@ use crate::error::GitError;
 use crate::types::Result;
 
 /// Trait for provider-specific operations.
+#[cfg_attr(feature = "async", async_trait::async_trait)]
 pub trait ProviderOperations {
     /// Gets the provider type.
     fn provider_type(&self) -> ProviderType;
@@ -22,8 +23,60 @@ pub trait ProviderOperations {
     /// Checks if a repository URL belongs to this provider.
     fn matches_url(&self, url: &str) -> bool;
 
+    /// Extracts `(owner, repo)` from a URL this provider claims (`matches_url` returned
+    /// `true`). Returns `GitError::InvalidUrl` if the URL doesn't actually match.
+    fn parse_owner_repo(&self, url: &str) -> Result<(String, String)>;
+
     /// Creates a client for the provider with optional authentication.
-    fn create_client(&self, auth_token: Option<String>) -> Result<Box<dyn ProviderClient>>;
+    fn create_client(&self, auth: Option<Auth>) -> Result<Box<dyn ProviderClient>>;
+
+    /// Creates a client with fuller control over TLS configuration, e.g. for a
+    /// self-hosted instance behind a private/self-signed CA.
+    ///
+    /// The default implementation ignores `options.root_certificate_pem` and simply
+    /// delegates to `create_client`; providers that make real HTTP calls should
+    /// override this to load the certificate into their HTTP client builder.
+    fn create_client_with_options(&self, options: ClientOptions) -> Result<Box<dyn ProviderClient>> {
+        self.create_client(options.auth)
+    }
+
+    /// Clones this provider into a boxed trait object, so registries can store a
+    /// heterogeneous collection of providers.
+    fn clone_box(&self) -> Box<dyn ProviderOperations>;
+
+    /// Creates an async client for the provider with optional authentication and TLS
+    /// configuration, for callers that want to integrate provider lookups into a
+    /// tokio-based pipeline instead of blocking a thread per request.
+    ///
+    /// Only available when the `async` feature is enabled.
+    #[cfg(feature = "async")]
+    async fn create_async_client(&self, options: ClientOptions) -> Result<Box<dyn AsyncProviderClient>>;
+}
+
+/// Options controlling how a provider client is constructed.
+#[derive(Debug, Clone, Default)]
+pub struct ClientOptions {
+    /// Authentication credentials, if any.
+    pub auth: Option<Auth>,
+    /// A PEM-encoded root certificate to additionally trust, for self-hosted instances
+    /// behind a private or self-signed CA.
+    pub root_certificate_pem: Option<Vec<u8>>,
+}
+
+/// Authentication credentials for a provider client.
+///
+/// Each provider maps these to the header scheme it actually expects; the caller just
+/// picks the credential kind it has, without needing to know the wire format.
+#[derive(Debug, Clone)]
+pub enum Auth {
+    /// A personal access token, e.g. a GitHub PAT or a GitLab personal/project token.
+    Token(String),
+    /// HTTP Basic authentication via a username and password (or username and
+    /// app/API password, for providers that use that convention).
+    UsernamePassword(String, String),
+    /// A GitLab CI job token (`CI_JOB_TOKEN`), sent via the `JOB-TOKEN` header by jobs
+    /// running inside a pipeline.
+    CiJobToken(String),
 }
 
 /// Enum representing different provider types.
@@ -43,26 +96,233 @@ pub trait ProviderClient {
     /// Checks if the client is authenticated.
     fn is_authenticated(&self) -> bool;
 
-    /// Gets pull/merge requests for a repository.
-    fn get_pull_requests(&self, owner: &str, repo: &str) -> Result<Vec<PullRequest>>;
+    /// Gets pull/merge requests for a repository, following every page of results.
+    ///
+    /// `options` lets callers filter by state (e.g. only open items) and choose a page
+    /// size; `None` requests the provider's defaults.
+    fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>>;
 
-    /// Gets issues for a repository.
-    fn get_issues(&self, owner: &str, repo: &str) -> Result<Vec<Issue>>;
+    /// Gets issues for a repository, following every page of results.
+    fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>>;
 
     /// Gets repository metadata.
     fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo>;
+
+    /// Opens a new pull/merge request.
+    ///
+    /// Returns `GitError::WriteOperationNotPermitted` if the client has no credentials,
+    /// since every provider requires authentication to create a pull/merge request.
+    /// The generic and self-hosted fallback clients return `GitError::AnalysisError`
+    /// instead, whatever their credentials.
+    fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest>;
+
+    /// Creates a new issue.
+    ///
+    /// Returns `GitError::WriteOperationNotPermitted` if the client has no credentials.
+    /// The generic and self-hosted fallback clients return `GitError::AnalysisError`
+    /// instead, whatever their credentials.
+    fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue>;
+}
+
+/// Trait for provider-specific client operations performed asynchronously.
+///
+/// Mirrors `ProviderClient`, but every network call returns a future instead of
+/// blocking the calling thread, so callers can integrate provider lookups into a
+/// tokio-based pipeline.
+///
+/// Only available when the `async` feature is enabled.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncProviderClient: Send + Sync {
+    /// Gets the provider type.
+    fn provider_type(&self) -> ProviderType;
+
+    /// Checks if the client is authenticated.
+    fn is_authenticated(&self) -> bool;
+
+    /// Gets pull/merge requests for a repository, following every page of results.
+    async fn get_pull_requests(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<PullRequest>>;
+
+    /// Gets issues for a repository, following every page of results.
+    async fn get_issues(&self, owner: &str, repo: &str, options: Option<ListOptions>) -> Result<Vec<Issue>>;
+
+    /// Gets repository metadata.
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> Result<RepositoryInfo>;
+
+    /// Opens a new pull/merge request.
+    ///
+    /// Returns `GitError::WriteOperationNotPermitted` if the client has no credentials,
+    /// since every provider requires authentication to create a pull/merge request.
+    /// The generic and self-hosted fallback clients return `GitError::AnalysisError`
+    /// instead, whatever their credentials.
+    async fn create_pull_request(&self, owner: &str, repo: &str, request: CreatePullRequest) -> Result<PullRequest>;
+
+    /// Creates a new issue.
+    ///
+    /// Returns `GitError::WriteOperationNotPermitted` if the client has no credentials.
+    /// The generic and self-hosted fallback clients return `GitError::AnalysisError`
+    /// instead, whatever their credentials.
+    async fn create_issue(&self, owner: &str, repo: &str, request: CreateIssue) -> Result<Issue>;
+}
+
+/// Filters applied when listing a paginated provider resource (pull/merge requests,
+/// issues).
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    /// Restrict results to a particular state, e.g. `"open"`/`"closed"` (GitHub) or
+    /// `"opened"`/`"closed"`/`"merged"` (GitLab). `None` requests the provider's default.
+    pub state: Option<String>,
+    /// Page size to request from the provider. `None` uses the provider's default.
+    pub per_page: Option<u32>,
+}
+
+/// Maximum number of page requests allowed in flight at once when paginating a list
+/// endpoint.
+pub(crate) const MAX_PARALLEL_PAGES: usize = 32;
+
+/// Fetches pages `2..=total_pages` with up to `MAX_PARALLEL_PAGES` requests in flight at
+/// once via `fetch_page`, then appends their items (in page order) after `first_page`.
+///
+/// `fetch_page` must be safe to call concurrently from multiple threads.
+pub(crate) fn paginate<T, F>(first_page: Vec<T>, total_pages: usize, fetch_page: F) -> Result<Vec<T>>
+where
+    T: Send,
+    F: Fn(usize) -> Result<Vec<T>> + Sync,
+{
+    if total_pages <= 1 {
+        return Ok(first_page);
+    }
+
+    let remaining: Vec<usize> = (2..=total_pages).collect();
+    let results = std::sync::Mutex::new(Vec::<(usize, Vec<T>)>::new());
+    let error = std::sync::Mutex::new(None::<GitError>);
+
+    for chunk in remaining.chunks(MAX_PARALLEL_PAGES) {
+        std::thread::scope(|scope| {
+            for &page in chunk {
+                let fetch_page = &fetch_page;
+                let results = &results;
+                let error = &error;
+                scope.spawn(move || match fetch_page(page) {
+                    Ok(items) => results.lock().unwrap().push((page, items)),
+                    Err(e) => {
+                        let mut slot = error.lock().unwrap();
+                        if slot.is_none() {
+                            *slot = Some(e);
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    if let Some(e) = error.into_inner().unwrap() {
+        return Err(e);
+    }
+
+    let mut paged = results.into_inner().unwrap();
+    paged.sort_by_key(|(page, _)| *page);
+
+    let mut all = first_page;
+    for (_, items) in paged {
+        all.extend(items);
+    }
+
+    Ok(all)
+}
+
+/// Maps a `GitUrl::from_shorthand` alias prefix (`"gh"`, `"gl"`, `"bb"`) to the
+/// `ProviderType` that owns the corresponding host, mirroring `types::SHORTHAND_ALIASES`
+/// so a shorthand's expanded host and its provider type never drift apart.
+pub fn shorthand_provider_type(prefix: &str) -> Option<ProviderType> {
+    match prefix {
+        "gh" => Some(ProviderType::GitHub),
+        "gl" => Some(ProviderType::GitLab),
+        "bb" => Some(ProviderType::Bitbucket),
+        _ => None,
+    }
 }
 
 /// Factory function to create a provider based on a repository URL.
+///
+/// Consults a default `ProviderRegistry` covering the public GitHub, GitLab, and
+/// Bitbucket hosts. To analyze a self-hosted instance (GitHub Enterprise, self-managed
+/// GitLab), build a `ProviderRegistry` directly and register a provider constructed via
+/// `GitHubProvider::with_host`/`GitLabProvider::with_host`.
 pub fn provider_for_url(url: &str) -> Result<Box<dyn ProviderOperations>> {
-    if github::GitHubProvider::new().matches_url(url) {
-        Ok(Box::new(github::GitHubProvider::new()))
-    } else if gitlab::GitLabProvider::new().matches_url(url) {
-        Ok(Box::new(gitlab::GitLabProvider::new()))
-    } else if bitbucket::BitbucketProvider::new().matches_url(url) {
-        Ok(Box::new(bitbucket::BitbucketProvider::new()))
-    } else {
-        Ok(Box::new(generic::GenericProvider::new()))
+    Ok(ProviderRegistry::new().resolve(url))
+}
+
+/// A registry of providers consulted in registration order to resolve a repository URL,
+/// falling back to the generic provider when nothing claims it.
+///
+/// This lets callers register providers for self-hosted domains (e.g. `git.mycorp.com`)
+/// alongside the public SaaS hosts, without the crate hard-coding those hostnames.
+pub struct ProviderRegistry {
+    providers: Vec<std::sync::Arc<dyn ProviderOperations>>,
+}
+
+impl ProviderRegistry {
+    /// Creates a registry pre-populated with the public GitHub, GitLab, and Bitbucket
+    /// providers.
+    pub fn new() -> Self {
+        ProviderRegistry {
+            providers: vec![
+                std::sync::Arc::new(github::GitHubProvider::new()),
+                std::sync::Arc::new(gitlab::GitLabProvider::new()),
+                std::sync::Arc::new(bitbucket::BitbucketProvider::new()),
+            ],
+        }
+    }
+
+    /// Creates an empty registry with no providers registered.
+    pub fn empty() -> Self {
+        ProviderRegistry {
+            providers: Vec::new(),
+        }
+    }
+
+    /// Registers an additional provider, e.g. one built with `GitHubProvider::with_host`
+    /// for a self-hosted instance, or a `generic::SelfHostedProvider` for a platform with
+    /// no dedicated provider struct. Providers are tried in registration order.
+    pub fn register(&mut self, provider: std::sync::Arc<dyn ProviderOperations>) {
+        self.providers.push(provider);
+    }
+
+    /// Resolves the best-matching provider for `url`, falling back to the generic
+    /// provider when none of the registered providers claim it.
+    pub fn resolve(&self, url: &str) -> Box<dyn ProviderOperations> {
+        for provider in &self.providers {
+            if provider.matches_url(url) {
+                return provider.clone_box();
+            }
+        }
+
+        Box::new(generic::GenericProvider::new())
+    }
+
+    /// Resolves `url` against the registered providers and extracts its owner and
+    /// repository name in one step, so callers don't need to call each provider's
+    /// `matches_url`/`parse_owner_repo` by hand.
+    ///
+    /// Returns `None` if no registered provider claims `url` (the generic fallback is not
+    /// consulted here, since it has no reliable way to parse an arbitrary host's URL format).
+    pub fn detect(&self, url: &str) -> Option<(ProviderType, String, String)> {
+        for provider in &self.providers {
+            if provider.matches_url(url) {
+                if let Ok((owner, repo)) = provider.parse_owner_repo(url) {
+                    return Some((provider.provider_type(), owner, repo));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl Default for ProviderRegistry {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -90,6 +350,15 @@ pub enum PullRequestState {
     Merged,
 }
 
+/// Parameters for opening a new pull/merge request.
+#[derive(Debug, Clone)]
+pub struct CreatePullRequest {
+    pub title: String,
+    pub description: Option<String>,
+    pub source_branch: String,
+    pub target_branch: String,
+}
+
 /// Issue information.
 #[derive(Debug, Clone)]
 pub struct Issue {
@@ -104,6 +373,13 @@ pub struct Issue {
     pub url: String,
 }
 
+/// Parameters for creating a new issue.
+#[derive(Debug, Clone)]
+pub struct CreateIssue {
+    pub title: String,
+    pub description: Option<String>,
+}
+
 /// Issue state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IssueState {
@@ -124,4 +400,46 @@ pub struct RepositoryInfo {
     pub default_branch: String,
     pub homepage: Option<String>,
     pub url: String,
+}
+
+/// Parses an RFC 3339 UTC timestamp (e.g. `2021-04-01T00:00:00Z`), as returned by both
+/// the GitHub and GitLab APIs, into a Unix timestamp. Fractional seconds and explicit
+/// `+00:00`-style offsets are tolerated but ignored.
+pub(crate) fn parse_rfc3339_timestamp(s: &str) -> Option<u64> {
+    let s = s.trim().trim_end_matches('Z');
+    let (date_part, time_part) = s.split_once('T')?;
+    let time_part = time_part
+        .split(|c| c == '+' || c == '.' || c == '-')
+        .next()?;
+
+    let mut date_iter = date_part.split('-');
+    let year: i64 = date_iter.next()?.parse().ok()?;
+    let month: u32 = date_iter.next()?.parse().ok()?;
+    let day: u32 = date_iter.next()?.parse().ok()?;
+
+    let mut time_iter = time_part.split(':');
+    let hour: i64 = time_iter.next()?.parse().ok()?;
+    let minute: i64 = time_iter.next()?.parse().ok()?;
+    let second: i64 = time_iter.next()?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second;
+
+    if seconds < 0 {
+        None
+    } else {
+        Some(seconds as u64)
+    }
+}
+
+/// Days since the Unix epoch for a civil (Gregorian) date, using Howard Hinnant's
+/// `days_from_civil` algorithm.
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let mp = (m as i64 + 9) % 12; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 //! Provides the core Repository implementation for GitLens.
 
-use crate::error::GitError;
+use crate::error::{GitError, RemoteMismatch};
 use crate::types::{BranchName, CommitHash, GitUrl, Remote, Result};
 use crate::models::*;
 
@@ -38,13 +38,107 @@ impl Default for CloneOptions {
     }
 }
 
+/// Options controlling how a diff is computed and parsed.
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    /// Number of context lines to include around each hunk (`--unified=<n>`).
+    pub context_lines: u32,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions { context_lines: 3 }
+    }
+}
+
+/// The well-known empty tree object every Git repository contains, useful as a diff base
+/// when there is no real "before" revision (e.g. diffing a root commit, or producing a
+/// full initial snapshot of the repository as pure additions).
+pub const EMPTY_TREE_OID: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
+
+/// Options controlling which refs `ls_remote` returns.
+#[derive(Debug, Clone)]
+pub struct LsRemoteOptions {
+    /// Only list branches (`refs/heads/*`).
+    pub heads: bool,
+    /// Only list tags (`refs/tags/*`).
+    pub tags: bool,
+}
+
+impl Default for LsRemoteOptions {
+    fn default() -> Self {
+        LsRemoteOptions {
+            heads: false,
+            tags: false,
+        }
+    }
+}
+
+/// Options controlling a `Repository::fetch` call.
+#[derive(Debug, Clone)]
+pub struct FetchOptions {
+    /// Remove remote-tracking refs that no longer exist on the remote (`--prune`).
+    pub prune: bool,
+    /// Also fetch tags (`--tags`).
+    pub tags: bool,
+    /// Limit history depth (`--depth=N`).
+    pub depth: Option<u32>,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        FetchOptions {
+            prune: false,
+            tags: false,
+            depth: None,
+        }
+    }
+}
+
 /// Represents a local Git repository for read-only analysis.
 #[derive(Debug, Clone)]
 pub struct Repository {
     pub(crate) location: PathBuf,
+    /// When `true`, author/committer identities are resolved through the repository's
+    /// `.mailmap` file (`--use-mailmap`, `%aN`/`%aE`) before being returned.
+    pub(crate) use_mailmap: bool,
 }
 
 impl Repository {
+    /// Returns a copy of this repository handle with mailmap-based author normalization
+    /// toggled for subsequent `get_commit`, `get_commit_history`, and `blame` calls.
+    pub fn with_mailmap(mut self, enabled: bool) -> Self {
+        self.use_mailmap = enabled;
+        self
+    }
+
+    /// Reads and parses the repository's `.mailmap` file.
+    ///
+    /// # Returns
+    /// A vector of `MailmapEntry` describing how aliased identities collapse into a
+    /// canonical one. Returns an empty vector if no `.mailmap` file is present.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn read_mailmap(&self) -> Result<Vec<MailmapEntry>> {
+        let toplevel = execute_git_fn(&self.location, &["rev-parse", "--show-toplevel"], |output| {
+            Ok(output.trim().to_string())
+        })?;
+
+        let mailmap_path = PathBuf::from(toplevel).join(".mailmap");
+        let contents = match std::fs::read_to_string(&mailmap_path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(GitError::FileSystemError(e.to_string())),
+        };
+
+        Ok(contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(MailmapEntry::parse_line)
+            .collect())
+    }
     /// Opens an existing Git repository for analysis.
     ///
     /// This checks if the path is actually a valid Git repository
@@ -58,6 +152,7 @@ impl Repository {
     pub fn open<P: AsRef<Path>>(p: P) -> Result<Repository> {
         let repo = Repository {
             location: PathBuf::from(p.as_ref()),
+            use_mailmap: false,
         };
 
         // Verify this is actually a git repository
@@ -130,6 +225,118 @@ impl Repository {
         Self::open(p_ref)
     }
 
+    /// Lists the refs (branches, tags, and HEAD) advertised by a remote repository,
+    /// without cloning it.
+    ///
+    /// Equivalent to `git ls-remote [--heads] [--tags] <url>`.
+    ///
+    /// # Arguments
+    /// * `url` - The URL of the remote repository.
+    /// * `opts` - Options restricting which kinds of refs are listed.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn ls_remote(url: &GitUrl, opts: LsRemoteOptions) -> Result<Vec<RemoteRef>> {
+        let cwd = env::current_dir().map_err(|_| GitError::WorkingDirectoryInaccessible)?;
+
+        let mut args = vec!["ls-remote"];
+
+        if opts.heads {
+            args.push("--heads");
+        }
+
+        if opts.tags {
+            args.push("--tags");
+        }
+
+        let url_str = url.to_string();
+        args.push(&url_str);
+
+        let output = execute_git_fn(&cwd, &args, |output| Ok(output.to_string()))?;
+
+        parse_ls_remote_output(&output)
+    }
+
+    /// Lists the refs advertised by one of this repository's configured remotes,
+    /// without fetching.
+    ///
+    /// Equivalent to `git ls-remote [--heads] [--tags] <remote_name>`.
+    ///
+    /// # Arguments
+    /// * `remote_name` - The name of a configured remote.
+    /// * `opts` - Options restricting which kinds of refs are listed.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn ls_remote_for(&self, remote_name: &Remote, opts: LsRemoteOptions) -> Result<Vec<RemoteRef>> {
+        let mut args = vec!["ls-remote"];
+
+        if opts.heads {
+            args.push("--heads");
+        }
+
+        if opts.tags {
+            args.push("--tags");
+        }
+
+        let remote_str = remote_name.to_string();
+        args.push(&remote_str);
+
+        let output = execute_git_fn(&self.location, &args, |output| Ok(output.to_string()))?;
+
+        parse_ls_remote_output(&output)
+    }
+
+    /// Fetches updated refs from a remote without merging or checking out anything.
+    ///
+    /// Equivalent to `git fetch [--prune] [--tags] [--depth=N] <remote>`.
+    ///
+    /// # Arguments
+    /// * `remote` - The configured remote to fetch from.
+    /// * `opts` - Options controlling pruning, tags, and fetch depth.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`) if the fetch itself fails; ref parsing
+    /// never fails a successful fetch.
+    pub fn fetch(&self, remote: &Remote, opts: FetchOptions) -> Result<FetchSummary> {
+        let mut args: Vec<String> = vec!["fetch".to_string()];
+
+        if opts.prune {
+            args.push("--prune".to_string());
+        }
+
+        if opts.tags {
+            args.push("--tags".to_string());
+        }
+
+        if let Some(depth) = opts.depth {
+            args.push(format!("--depth={}", depth));
+        }
+
+        args.push(remote.to_string());
+
+        // `git fetch` reports the updated-ref table on stderr, so we inspect the raw
+        // process output rather than going through `execute_git_fn`.
+        let output = Command::new("git")
+            .current_dir(&self.location)
+            .args(&args)
+            .output();
+
+        match output {
+            Ok(out) if out.status.success() => {
+                let stderr = str::from_utf8(&out.stderr).unwrap_or_default();
+                Ok(parse_fetch_output(stderr))
+            }
+            Ok(out) => {
+                let stdout = str::from_utf8(&out.stdout).unwrap_or_default().to_string();
+                let stderr = str::from_utf8(&out.stderr).unwrap_or_default().to_string();
+                Err(GitError::GitError { stdout, stderr })
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(GitError::GitNotFound),
+            Err(_) => Err(GitError::Execution),
+        }
+    }
+
     /// Lists the names of all local branches.
     ///
     /// Equivalent to `git branch --format='%(refname:short)'`.
@@ -276,26 +483,69 @@ impl Repository {
     /// # Errors
     /// Returns `GitError` (including `GitNotFound`).
     pub fn get_commit(&self, commit_ref: Option<&str>) -> Result<Commit> {
-        let format = "%H%n\
-                     shortcommit %h%n\
-                     author_name %an%n\
-                     author_email %ae%n\
-                     timestamp %at%n\
-                     %P%n\
-                     message %s";
+        self.get_commit_opts(commit_ref, false)
+    }
 
+    /// Gets detailed information about a commit, optionally verifying its signature.
+    ///
+    /// # Arguments
+    /// * `commit_ref` - The commit reference (hash, branch name, etc.). If `None`, uses HEAD.
+    /// * `verify_signature` - If `true`, runs `git verify-commit --raw` and attaches the
+    ///   resulting `SignatureStatus` to the returned `Commit`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn get_commit_opts(&self, commit_ref: Option<&str>, verify_signature: bool) -> Result<Commit> {
+        let format = if self.use_mailmap { COMMIT_LOG_FORMAT_MAILMAP } else { COMMIT_LOG_FORMAT };
         let format_string = format!("--format={}", format);
-        let args = match commit_ref {
-            Some(c) => vec!["show", "--no-patch", &format_string, c],
-            None => vec!["show", "--no-patch", &format_string],
-        };
+        let mut args = vec!["show", "--no-patch"];
+        if self.use_mailmap {
+            args.push("--use-mailmap");
+        }
+        args.push(&format_string);
+        if let Some(c) = commit_ref {
+            args.push(c);
+        }
 
-        execute_git_fn(&self.location, args, |output| {
-            Commit::from_show_format(output).ok_or_else(|| GitError::GitError {
+        let mut commit = execute_git_fn(&self.location, args, |output| {
+            // The format begins with a leading record separator; strip it before parsing.
+            Commit::from_record(output.trim_start_matches('\x1e').trim_end()).ok_or_else(|| GitError::GitError {
                 stdout: output.to_string(),
                 stderr: "Failed to parse commit information".to_string(),
             })
-        })
+        })?;
+
+        if verify_signature {
+            commit.signature = Some(self.verify_commit_signature(commit_ref.unwrap_or("HEAD"))?);
+        }
+
+        Ok(commit)
+    }
+
+    /// Verifies the GPG/SSH signature of a commit.
+    ///
+    /// Equivalent to `git verify-commit --raw <commit_ref>`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`). An unsigned or badly signed commit is
+    /// reported via `SignatureStatus`, not as an `Err`.
+    pub fn verify_commit_signature(&self, commit_ref: &str) -> Result<SignatureStatus> {
+        // `verify-commit` exits non-zero for unsigned/bad commits, so we inspect stdout+stderr
+        // from the raw process invocation rather than treating a failed status as an error.
+        let output = Command::new("git")
+            .current_dir(&self.location)
+            .args(["verify-commit", "--raw", commit_ref])
+            .output();
+
+        match output {
+            Ok(out) => {
+                let stdout = str::from_utf8(&out.stdout).unwrap_or_default();
+                let stderr = str::from_utf8(&out.stderr).unwrap_or_default();
+                Ok(SignatureStatus::from_gpg_output(&format!("{}\n{}", stdout, stderr)))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(GitError::GitNotFound),
+            Err(_) => Err(GitError::Execution),
+        }
     }
 
     /// Gets the current status of the repository.
@@ -397,41 +647,28 @@ impl Repository {
     pub fn list_branches_info(&self) -> Result<Vec<Branch>> {
         execute_git_fn(
             &self.location,
-            &["branch", "--list", "-v", "--format=%(refname:short) %(objectname) %(HEAD) %(upstream:short)"],
-            |output| {
-                let mut branches = Vec::new();
-
-                for line in output.lines() {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 3 {
-                        let name_str = parts[0];
-                        let commit_str = parts[1];
-                        let is_head = parts[2] == "*";
-
-                        let upstream = if parts.len() >= 4 {
-                            Some(parts[3].to_string())
-                        } else {
-                            None
-                        };
+            &["branch", "--list", "-v", &format!("--format={}", BRANCH_LIST_FORMAT)],
+            parse_branch_list_output,
+        )
+    }
 
-                        if let Ok(name) = BranchName::from_str(name_str) {
-                            if let Ok(commit_hash) = CommitHash::from_str(commit_str) {
-                                branches.push(Branch {
-                                    name,
-                                    commit: commit_hash,
-                                    is_head,
-                                    upstream,
-                                });
-                            } else {
-                                eprintln!("Warning: Could not parse commit hash '{}' for branch '{}'", commit_str, name_str);
-                            }
-                        } else {
-                            eprintln!("Warning: Could not parse branch name '{}'", name_str);
-                        }
-                    }
-                }
-                Ok(branches)
-            }
+    /// Lists branches with detailed information, sorted by descending last-commit recency.
+    ///
+    /// Equivalent to
+    /// `git for-each-ref --sort=-committerdate --format='<BRANCH_LIST_FORMAT>' refs/heads/`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_branches_by_recency(&self) -> Result<Vec<Branch>> {
+        execute_git_fn(
+            &self.location,
+            &[
+                "for-each-ref",
+                "--sort=-committerdate",
+                &format!("--format={}", BRANCH_LIST_FORMAT),
+                "refs/heads/",
+            ],
+            parse_branch_list_output,
         )
     }
 
@@ -453,29 +690,47 @@ impl Repository {
         skip: Option<usize>,
         branch: Option<&BranchName>,
     ) -> Result<Vec<Commit>> {
-        let mut args = vec!["log"];
+        self.get_commit_history_opts(limit, skip, branch, false)
+    }
 
-        // Format string for parsing commit info
-        let format_arg = format!(
-            "--format=%H%n{}%n{}%n{}%n{}%n{}%n%P%n{}",
-            "shortcommit %h",
-            "author_name %an",
-            "author_email %ae",
-            "timestamp %at",
-            "message %s",
-        );
+    /// Gets a list of commits in the repository history, optionally verifying each signature.
+    ///
+    /// # Arguments
+    /// * `limit` - Optional maximum number of commits to return.
+    /// * `skip` - Optional number of commits to skip from the beginning.
+    /// * `branch` - Optional branch name to get history for. If None, uses current branch.
+    /// * `verify_signatures` - If `true`, runs `git verify-commit --raw` for each commit and
+    ///   attaches the resulting `SignatureStatus`. This costs one extra process per commit.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn get_commit_history_opts(
+        &self,
+        limit: Option<usize>,
+        skip: Option<usize>,
+        branch: Option<&BranchName>,
+        verify_signatures: bool,
+    ) -> Result<Vec<Commit>> {
+        let mut args = vec!["log"];
+        if self.use_mailmap {
+            args.push("--use-mailmap");
+        }
 
+        let format = if self.use_mailmap { COMMIT_LOG_FORMAT_MAILMAP } else { COMMIT_LOG_FORMAT };
+        let format_arg = format!("--format={}", format);
         args.push(&format_arg);
 
         // Apply limit and skip
-        if let Some(limit_val) = limit {
+        let limit_str = limit.map(|v| v.to_string());
+        if let Some(limit_val) = &limit_str {
             args.push("--max-count");
-            args.push(&limit_val.to_string());
+            args.push(limit_val);
         }
 
-        if let Some(skip_val) = skip {
+        let skip_str = skip.map(|v| v.to_string());
+        if let Some(skip_val) = &skip_str {
             args.push("--skip");
-            args.push(&skip_val.to_string());
+            args.push(skip_val);
         }
 
         // If branch is specified, add it to the command
@@ -483,22 +738,18 @@ impl Repository {
             args.push(b.as_ref());
         }
 
-        // Execute command
-        let output = self.cmd_out(&args)?;
+        // Execute command, keeping the raw output so `\x1e`/`\x1f` separators survive intact.
+        let output = execute_git_fn(&self.location, &args, |output| Ok(output.to_string()))?;
 
-        // Parse commits
+        // Parse commits: each record is preceded by a `\x1e` record separator.
         let mut commits = Vec::new();
-        let mut current_lines = Vec::new();
-
-        for line in output {
-            current_lines.push(line);
 
-            // Each commit has 7 lines in our format
-            if current_lines.len() == 7 {
-                if let Some(commit) = Commit::from_show_format(&current_lines.join("\n")) {
-                    commits.push(commit);
+        for record in output.split('\x1e').filter(|r| !r.is_empty()) {
+            if let Some(mut commit) = Commit::from_record(record.trim_end_matches('\n')) {
+                if verify_signatures {
+                    commit.signature = Some(self.verify_commit_signature(&commit.hash.to_string())?);
                 }
-                current_lines.clear();
+                commits.push(commit);
             }
         }
 
@@ -509,24 +760,29 @@ impl Repository {
     ///
     /// # Arguments
     /// * `file_path` - Path to the file, relative to repository root.
+    /// * `rev` - The revision to blame as of, instead of the working tree/`HEAD`.
     ///
     /// # Returns
     /// A vector of `BlameLine` structs with line-by-line blame information.
     ///
     /// # Errors
     /// Returns `GitError` (including `GitNotFound`).
-    pub fn blame<P: AsRef<Path>>(&self, file_path: P) -> Result<Vec<BlameLine>> {
-        let output = execute_git_fn(
-            &self.location,
-            &[
-                "blame",
-                "--porcelain",
-                file_path.as_ref().to_str().ok_or_else(|| {
-                    GitError::PathEncodingError(PathBuf::from(file_path.as_ref()))
-                })?,
-            ],
-            |output| Ok(output.to_string()),
-        )?;
+    pub fn blame<P: AsRef<Path>>(&self, file_path: P, rev: Option<&str>) -> Result<Vec<BlameLine>> {
+        let path_arg = file_path.as_ref().to_str().ok_or_else(|| {
+            GitError::PathEncodingError(PathBuf::from(file_path.as_ref()))
+        })?;
+
+        let mut args = vec!["blame", "--porcelain"];
+        if self.use_mailmap {
+            args.push("--use-mailmap");
+        }
+        if let Some(rev) = rev {
+            args.push(rev);
+        }
+        args.push("--");
+        args.push(path_arg);
+
+        let output = execute_git_fn(&self.location, &args, |output| Ok(output.to_string()))?;
 
         let mut blame_lines = Vec::new();
         let mut current_hash: Option<CommitHash> = None;
@@ -538,39 +794,42 @@ impl Repository {
         for line in output.lines() {
             if line.starts_with('\t') {
                 // Content line
-                if let (Some(hash), line_no, fin_line_no) =
-                    (current_hash.clone(), current_line_no, final_line_no) {
+                if let Some(hash) = current_hash.clone() {
                     blame_lines.push(BlameLine {
                         hash,
                         author: current_author.clone(),
-                        original_line: line_no,
-                        final_line: fin_line_no,
+                        original_line: current_line_no,
+                        final_line: final_line_no,
                         timestamp: current_timestamp,
                         content: line[1..].to_string(),
                     });
                 }
-            } else if line.contains(' ') {
-                let parts: Vec<&str> = line.splitn(2, ' ').collect();
-                if parts.len() == 2 {
-                    match parts[0] {
-                        "author" => current_author = parts[1].to_string(),
-                        "author-time" => {
-                            current_timestamp = parts[1].parse().unwrap_or(0);
-                        }
-                        "original-line" => {
-                            current_line_no = parts[1].parse().unwrap_or(0);
-                        }
-                        "final-line" => {
-                            final_line_no = parts[1].parse().unwrap_or(0);
-                        }
-                        _ => {}
-                    }
-                }
-            } else if line.len() >= 40 {
-                // Commit hash
-                if let Ok(hash) = CommitHash::from_str(&line[0..40]) {
+                continue;
+            }
+
+            let mut parts = line.splitn(2, ' ');
+            let first = parts.next().unwrap_or("");
+            let rest = parts.next();
+
+            if first.len() == 40 && first.bytes().all(|b| b.is_ascii_hexdigit()) {
+                // Commit header line: "<sha> <orig-line> <final-line> [<num-lines-in-group>]".
+                // Git emits this line (with the original/final line numbers) for every line
+                // group, not just the first time a commit is seen.
+                if let Ok(hash) = CommitHash::from_str(first) {
                     current_hash = Some(hash);
                 }
+                let mut numbers = rest.unwrap_or("").split_whitespace();
+                current_line_no = numbers.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                final_line_no = numbers.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                continue;
+            }
+
+            if let Some(rest) = rest {
+                match first {
+                    "author" => current_author = rest.to_string(),
+                    "author-time" => current_timestamp = rest.parse().unwrap_or(0),
+                    _ => {}
+                }
             }
         }
 
@@ -585,6 +844,21 @@ impl Repository {
     /// # Errors
     /// Returns `GitError` (including `GitNotFound`).
     pub fn list_tags(&self) -> Result<Vec<TagInfo>> {
+        self.list_tags_opts(false)
+    }
+
+    /// Gets information about tags in the repository, optionally verifying each signature.
+    ///
+    /// # Arguments
+    /// * `verify_signatures` - If `true`, runs `git verify-tag --raw` for annotated tags and
+    ///   attaches the resulting `SignatureStatus`.
+    ///
+    /// # Returns
+    /// A vector of `TagInfo` structs with tag details.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_tags_opts(&self, verify_signatures: bool) -> Result<Vec<TagInfo>> {
         let output = self.cmd_out([
             "tag",
             "--list",
@@ -622,11 +896,18 @@ impl Repository {
                         None
                     };
 
+                    let signature = if verify_signatures && obj_type == "tag" {
+                        Some(self.verify_tag_signature(name_str)?)
+                    } else {
+                        None
+                    };
+
                     tags.push(TagInfo {
                         name,
                         target,
                         annotated: obj_type == "tag",
                         message,
+                        signature,
                     });
                 }
             }
@@ -635,6 +916,213 @@ impl Repository {
         Ok(tags)
     }
 
+    /// Verifies the GPG/SSH signature of an annotated tag.
+    ///
+    /// Equivalent to `git verify-tag --raw <tag_name>`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`). An unsigned or badly signed tag is
+    /// reported via `SignatureStatus`, not as an `Err`.
+    pub fn verify_tag_signature(&self, tag_name: &str) -> Result<SignatureStatus> {
+        let output = Command::new("git")
+            .current_dir(&self.location)
+            .args(["verify-tag", "--raw", tag_name])
+            .output();
+
+        match output {
+            Ok(out) => {
+                let stdout = str::from_utf8(&out.stdout).unwrap_or_default();
+                let stderr = str::from_utf8(&out.stderr).unwrap_or_default();
+                Ok(SignatureStatus::from_gpg_output(&format!("{}\n{}", stdout, stderr)))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(GitError::GitNotFound),
+            Err(_) => Err(GitError::Execution),
+        }
+    }
+
+    /// Lists the repository's stash entries, most recent first.
+    ///
+    /// Equivalent to `git stash list --format='%gd%x1f%gs'`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_stashes(&self) -> Result<Vec<StashEntry>> {
+        execute_git_fn(
+            &self.location,
+            &["stash", "list", "--format=%gd%x1f%gs"],
+            |output| {
+                let mut stashes = Vec::new();
+
+                for line in output.lines() {
+                    let mut parts = line.splitn(2, '\x1f');
+                    let reference_str = parts.next().unwrap_or("");
+                    let subject = parts.next().unwrap_or("");
+
+                    let reference = match crate::types::Stash::from_str(reference_str) {
+                        Ok(r) => r,
+                        Err(_) => continue,
+                    };
+
+                    let (branch, message) = parse_stash_subject(subject);
+
+                    stashes.push(StashEntry {
+                        reference,
+                        branch,
+                        message,
+                    });
+                }
+
+                Ok(stashes)
+            },
+        )
+    }
+
+    /// Lists the repository's worktrees, with the main worktree first.
+    ///
+    /// Equivalent to `git worktree list --porcelain`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_worktrees(&self) -> Result<Vec<Worktree>> {
+        execute_git_fn(
+            &self.location,
+            &["worktree", "list", "--porcelain"],
+            parse_worktree_list_output,
+        )
+    }
+
+    /// Lists every ref in the repository (local branches, remote-tracking branches, tags,
+    /// and notes).
+    ///
+    /// Equivalent to `git for-each-ref --format='%(refname)%x1f%(objectname)'`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_references(&self) -> Result<Vec<Reference>> {
+        execute_git_fn(
+            &self.location,
+            &["for-each-ref", "--format=%(refname)%x1f%(objectname)"],
+            |output| {
+                let mut refs = Vec::new();
+
+                for line in output.lines() {
+                    let mut parts = line.splitn(2, '\x1f');
+                    let name = parts.next().unwrap_or("");
+                    let oid_str = parts.next().unwrap_or("");
+
+                    let target = match CommitHash::from_str(oid_str) {
+                        Ok(oid) => oid,
+                        Err(_) => continue,
+                    };
+
+                    let ref_type = if name.starts_with("refs/heads/") {
+                        ReferenceType::LocalBranch
+                    } else if name.starts_with("refs/remotes/") {
+                        ReferenceType::RemoteBranch
+                    } else if name.starts_with("refs/tags/") {
+                        ReferenceType::Tag
+                    } else if name.starts_with("refs/notes/") {
+                        ReferenceType::Note
+                    } else {
+                        ReferenceType::Other
+                    };
+
+                    refs.push(Reference {
+                        name: name.to_string(),
+                        ref_type,
+                        target,
+                    });
+                }
+
+                Ok(refs)
+            },
+        )
+    }
+
+    /// Reads the content of a file at a given revision, without checking it out.
+    ///
+    /// Equivalent to `git show <rev>:<path>`.
+    ///
+    /// # Arguments
+    /// * `rev` - The revision (commit, branch, tag) to read the file from.
+    /// * `path` - The path to the file, relative to the repository root.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn read_blob(&self, rev: &str, path: &Path) -> Result<Vec<u8>> {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| GitError::PathEncodingError(path.to_path_buf()))?;
+        let spec = format!("{}:{}", rev, path_str);
+        execute_git_bytes(&self.location, &["show", &spec])
+    }
+
+    /// Reads a raw Git object by its object ID.
+    ///
+    /// Equivalent to `git cat-file -t/-s/-p <oid>`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn cat_object(&self, oid: &CommitHash) -> Result<GitObject> {
+        let oid_str = oid.to_string();
+
+        let kind_str = execute_git_fn(&self.location, &["cat-file", "-t", &oid_str], |output| {
+            Ok(output.trim().to_string())
+        })?;
+        let kind = GitObjectKind::from_str(&kind_str)?;
+
+        let size = execute_git_fn(&self.location, &["cat-file", "-s", &oid_str], |output| {
+            output
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| GitError::AnalysisError(format!("Invalid object size for {}", oid_str)))
+        })?;
+
+        let data = execute_git_bytes(&self.location, &["cat-file", "-p", &oid_str])?;
+
+        Ok(GitObject { kind, size, data })
+    }
+
+    /// Lists the entries of a tree at a given revision.
+    ///
+    /// Equivalent to `git ls-tree -z <rev>`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_tree(&self, rev: &str) -> Result<Vec<TreeEntry>> {
+        execute_git_fn(&self.location, &["ls-tree", "-z", rev], |output| {
+            output
+                .split('\0')
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| {
+                    let (info, name) = entry
+                        .split_once('\t')
+                        .ok_or_else(|| GitError::AnalysisError(format!("Malformed ls-tree entry: {}", entry)))?;
+                    let mut parts = info.split_whitespace();
+                    let mode = parts
+                        .next()
+                        .ok_or_else(|| GitError::AnalysisError("Missing mode in ls-tree entry".to_string()))?
+                        .to_string();
+                    let kind_str = parts
+                        .next()
+                        .ok_or_else(|| GitError::AnalysisError("Missing type in ls-tree entry".to_string()))?;
+                    let kind = GitObjectKind::from_str(kind_str)?;
+                    let oid_str = parts
+                        .next()
+                        .ok_or_else(|| GitError::AnalysisError("Missing oid in ls-tree entry".to_string()))?;
+                    let oid = CommitHash::from_str(oid_str)?;
+
+                    Ok(TreeEntry {
+                        mode,
+                        kind,
+                        oid,
+                        name: name.to_string(),
+                    })
+                })
+                .collect()
+        })
+    }
+
     /// Finds the common ancestor (merge base) of two commits or branches.
     ///
     /// # Arguments
@@ -704,6 +1192,9 @@ impl Repository {
         ])?;
         let differing_files = diff_output.len();
 
+        let conflicting_files = self.preview_merge_conflicts(&merge_base, source, target)?;
+        let can_fast_forward = ahead_count == 0;
+
         Ok(BranchDivergence {
             source: source.clone(),
             target: target.clone(),
@@ -711,9 +1202,108 @@ impl Repository {
             ahead_count,
             behind_count,
             differing_files,
+            conflicting_files,
+            can_fast_forward,
         })
     }
 
+    /// Simulates a no-commit, no-ff merge of `source` and `target` at their common ancestor to
+    /// preview which paths would conflict, without touching the working tree or index.
+    ///
+    /// Equivalent to `git merge-tree <merge_base> <source> <target>`. That command emits an
+    /// `our`/`their` stage line for every path changed on both sides even when it auto-merges
+    /// cleanly, so a path is only reported here if its diff body also contains a `<<<<<<<`
+    /// conflict marker.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    fn preview_merge_conflicts(
+        &self,
+        merge_base: &CommitHash,
+        source: &BranchName,
+        target: &BranchName,
+    ) -> Result<Vec<PathBuf>> {
+        let merge_base_str = merge_base.to_string();
+        let lines = self.cmd_out([
+            "merge-tree",
+            &merge_base_str,
+            source.as_ref(),
+            target.as_ref(),
+        ])?;
+
+        // `git merge-tree` prints an `our`/`their` stage line for every path changed on both
+        // sides, regardless of whether it auto-merged cleanly, so those lines alone aren't
+        // evidence of a conflict. A path only actually conflicts if its diff body carries
+        // `<<<<<<<`/`=======`/`>>>>>>>` markers, so track the path each block describes and
+        // only record it once we see those markers inside that block.
+        let mut conflicting_files = Vec::new();
+        let mut current_path: Option<PathBuf> = None;
+        for line in &lines {
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() {
+                current_path = None;
+                continue;
+            }
+
+            if let Some(rest) = trimmed
+                .strip_prefix("our ")
+                .or_else(|| trimmed.strip_prefix("their "))
+            {
+                if let Some(path) = rest.split_whitespace().last() {
+                    current_path = Some(PathBuf::from(path));
+                }
+                continue;
+            }
+
+            if trimmed.trim_start_matches(['+', '-', ' ']).starts_with("<<<<<<<") {
+                if let Some(path) = current_path.clone() {
+                    if !conflicting_files.contains(&path) {
+                        conflicting_files.push(path);
+                    }
+                }
+            }
+        }
+
+        Ok(conflicting_files)
+    }
+
+    /// Computes the file paths that differ between a `BranchDivergence`'s merge base and its
+    /// target tip, without re-running the divergence calculation.
+    ///
+    /// Equivalent to `git diff --name-only <merge_base> <target>`, optionally filtered to files
+    /// whose extension appears in `extensions` (e.g. `&["rs"]`).
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn changed_files(
+        &self,
+        divergence: &BranchDivergence,
+        extensions: Option<&[&str]>,
+    ) -> Result<Vec<PathBuf>> {
+        let merge_base_str = divergence.merge_base.to_string();
+        let lines = self.cmd_out([
+            "diff",
+            "--name-only",
+            &merge_base_str,
+            divergence.target.as_ref(),
+        ])?;
+
+        let paths = lines
+            .into_iter()
+            .map(PathBuf::from)
+            .filter(|path| match extensions {
+                None => true,
+                Some(exts) => path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .map(|ext| exts.iter().any(|e| e.trim_start_matches('.') == ext))
+                    .unwrap_or(false),
+            })
+            .collect();
+
+        Ok(paths)
+    }
+
     /// Gets information about remotes in the repository.
     ///
     /// # Returns
@@ -744,9 +1334,749 @@ impl Repository {
                 name: remote,
                 url,
                 fetch,
+                is_upstream: false,
             });
         }
 
         Ok(remote_infos)
     }
+
+    /// Identifies the remote most likely to be the project's canonical upstream.
+    ///
+    /// Scans every configured `remote.<name>.url` for one whose URL contains `pattern`
+    /// (e.g. an org/repo substring like `rust-lang`). When multiple remotes match,
+    /// prefers a remote literally named `upstream`, then `origin`, then the first match
+    /// found.
+    ///
+    /// # Returns
+    /// `Ok(None)` if no configured remote URL contains `pattern`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn find_upstream_remote(&self, pattern: &str) -> Result<Option<String>> {
+        // `git config --get-regex` exits non-zero when no key matches; that means no remotes.
+        let lines = match self.cmd_out(["config", "--local", "--get-regex", r"remote\..*\.url"]) {
+            Ok(lines) => lines,
+            Err(GitError::GitError { .. }) => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut matches = Vec::new();
+
+        for line in &lines {
+            let (key, url) = match line.split_once(' ') {
+                Some(pair) => pair,
+                None => continue,
+            };
+
+            if !url.contains(pattern) {
+                continue;
+            }
+
+            let mut parts = key.splitn(3, '.');
+            let name = match (parts.next(), parts.next(), parts.next()) {
+                (Some("remote"), Some(name), Some(_)) => name,
+                _ => continue,
+            };
+
+            matches.push(name.to_string());
+        }
+
+        if let Some(pos) = matches.iter().position(|name| name == "upstream") {
+            return Ok(Some(matches.remove(pos)));
+        }
+
+        if let Some(pos) = matches.iter().position(|name| name == "origin") {
+            return Ok(Some(matches.remove(pos)));
+        }
+
+        Ok(matches.into_iter().next())
+    }
+
+    /// Like `get_remotes_info`, but also flags which remote matches `pattern` as the upstream.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn get_remotes_info_upstream(&self, pattern: &str) -> Result<Vec<RemoteInfo>> {
+        let mut infos = self.get_remotes_info()?;
+        let upstream_name = self.find_upstream_remote(pattern)?;
+
+        if let Some(name) = upstream_name {
+            for info in &mut infos {
+                info.is_upstream = info.name.to_string() == name;
+            }
+        }
+
+        Ok(infos)
+    }
+
+    /// Validates that the repository's default push and fetch remotes both resolve to
+    /// `expected`.
+    ///
+    /// The fetch default is read from the current branch's `branch.<name>.remote`
+    /// config, falling back to `origin` if unset. The push default is read from
+    /// `branch.<name>.pushRemote`, then `remote.pushDefault`, falling back to the
+    /// resolved fetch remote (matching Git's own resolution order) if neither is set.
+    ///
+    /// # Errors
+    /// Returns a `RemoteMismatch` describing which check failed; this is a standalone
+    /// error type, not a `GitError`.
+    pub fn validate_remotes(&self, expected: &str) -> std::result::Result<(), RemoteMismatch> {
+        let current_branch = self
+            .cmd_out(["rev-parse", "--abbrev-ref", "HEAD"])
+            .ok()
+            .and_then(|lines| lines.into_iter().next())
+            .filter(|name| name != "HEAD");
+
+        let fetch_remote = match &current_branch {
+            Some(branch) => self
+                .cmd_out(["config", "--get", &format!("branch.{}.remote", branch)])
+                .ok()
+                .and_then(|lines| lines.into_iter().next())
+                .unwrap_or_else(|| "origin".to_string()),
+            None => return Err(RemoteMismatch::NoFetchRemote),
+        };
+
+        let push_remote = current_branch
+            .as_ref()
+            .and_then(|branch| {
+                self.cmd_out(["config", "--get", &format!("branch.{}.pushRemote", branch)])
+                    .ok()
+                    .and_then(|lines| lines.into_iter().next())
+            })
+            .or_else(|| {
+                self.cmd_out(["config", "--get", "remote.pushDefault"])
+                    .ok()
+                    .and_then(|lines| lines.into_iter().next())
+            })
+            .unwrap_or_else(|| fetch_remote.clone());
+
+        if push_remote.is_empty() {
+            return Err(RemoteMismatch::NoPushRemote);
+        }
+
+        if fetch_remote != expected || push_remote != expected {
+            return Err(RemoteMismatch::Mismatch {
+                expected: expected.to_string(),
+                push: push_remote,
+                fetch: fetch_remote,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Lists every branch on `remote` along with its ahead/behind counts against the
+    /// matching local tracking branch.
+    ///
+    /// Tries a single `git for-each-ref --format='%(refname:short) %(upstream:track)
+    /// %(upstream:trackshort)' refs/remotes/<remote>/` pass first; since remote-tracking
+    /// refs don't carry their own upstream config, this typically yields no tracking
+    /// info, so each branch falls back to a `git rev-list --left-right --count
+    /// <local>...<remote>/<branch>` against the same-named local branch, when one exists.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn list_remote_branches(&self, remote: &str) -> Result<Vec<RemoteBranchStatus>> {
+        let prefix = format!("refs/remotes/{}/", remote);
+        let lines = self.cmd_out([
+            "for-each-ref",
+            "--format=%(refname:short) %(upstream:track) %(upstream:trackshort)",
+            &prefix,
+        ])?;
+
+        let local_branches = self.list_branches().unwrap_or_default();
+        let mut statuses = Vec::new();
+
+        for line in lines {
+            let ref_short = match line.split_whitespace().next() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            if ref_short.ends_with("/HEAD") {
+                continue;
+            }
+
+            let branch_prefix = format!("{}/", remote);
+            let branch_short = ref_short.strip_prefix(&branch_prefix).unwrap_or(ref_short);
+
+            let (ahead, behind) = match local_branches
+                .iter()
+                .find(|b| b.to_string() == branch_short)
+            {
+                Some(local) => self
+                    .cmd_out([
+                        "rev-list",
+                        "--left-right",
+                        "--count",
+                        &format!("{}...{}", local, ref_short),
+                    ])
+                    .ok()
+                    .and_then(|lines| lines.into_iter().next())
+                    .map(|counts_line| {
+                        let mut nums = counts_line.split_whitespace();
+                        let ahead = nums.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+                        let behind = nums.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+                        (ahead, behind)
+                    })
+                    .unwrap_or((0, 0)),
+                None => (0, 0),
+            };
+
+            statuses.push(RemoteBranchStatus {
+                name: ref_short.to_string(),
+                ahead,
+                behind,
+            });
+        }
+
+        Ok(statuses)
+    }
+
+    /// Computes a structured diff between two revisions.
+    ///
+    /// Equivalent to `git diff --no-color -M -C --find-copies-harder -z --unified=<n> <from> <to>`.
+    ///
+    /// # Arguments
+    /// * `from` - The base revision.
+    /// * `to` - The revision to compare against `from`.
+    /// * `opts` - Options controlling the diff (e.g. context lines).
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn diff(&self, from: &str, to: &str, opts: DiffOptions) -> Result<DiffResult> {
+        let unified = format!("--unified={}", opts.context_lines);
+        execute_git_fn(
+            &self.location,
+            &[
+                "diff",
+                "--no-color",
+                "-M",
+                "-C",
+                "--find-copies-harder",
+                "-z",
+                &unified,
+                from,
+                to,
+            ],
+            parse_diff_output,
+        )
+    }
+
+    /// Computes a structured diff of the current uncommitted changes against `HEAD`.
+    ///
+    /// Equivalent to `git diff --no-color -M -C --find-copies-harder -z --unified=<n> HEAD`.
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn diff_working_tree(&self, opts: DiffOptions) -> Result<DiffResult> {
+        let unified = format!("--unified={}", opts.context_lines);
+        execute_git_fn(
+            &self.location,
+            &["diff", "--no-color", "-M", "-C", "--find-copies-harder", "-z", &unified, "HEAD"],
+            parse_diff_output,
+        )
+    }
+
+    /// Computes a structured diff with either endpoint optionally defaulted to a sentinel:
+    /// `from = None` means [`EMPTY_TREE_OID`] (so every tracked file appears as a pure
+    /// addition, e.g. for diffing a root commit or a "full initial snapshot"), and
+    /// `to = None` means the working tree, in which case `git status`'s untracked files are
+    /// merged in as additional pure-addition `DiffFile`s (plain `git diff` never reports
+    /// untracked files, only tracked ones).
+    ///
+    /// # Errors
+    /// Returns `GitError` (including `GitNotFound`).
+    pub fn diff_range(&self, from: Option<&str>, to: Option<&str>, opts: DiffOptions) -> Result<DiffResult> {
+        let from_rev = from.unwrap_or(EMPTY_TREE_OID);
+
+        let mut result = match to {
+            Some(to_rev) => self.diff(from_rev, to_rev, opts)?,
+            None => {
+                // `git diff <rev>` with a single revision compares it against the current
+                // index and working directory in one step, covering staged and unstaged
+                // changes to tracked files.
+                let unified = format!("--unified={}", opts.context_lines);
+                execute_git_fn(
+                    &self.location,
+                    &[
+                        "diff",
+                        "--no-color",
+                        "-M",
+                        "-C",
+                        "--find-copies-harder",
+                        "-z",
+                        &unified,
+                        from_rev,
+                    ],
+                    parse_diff_output,
+                )?
+            }
+        };
+
+        if to.is_none() {
+            let status = self.status()?;
+            for entry in status.files.iter().filter(|f| f.status == FileStatus::Untracked) {
+                result.files.push(self.untracked_diff_file(&entry.path));
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Builds a pure-addition `DiffFile` for an untracked file by reading its current
+    /// contents directly, since `git diff` never reports untracked paths.
+    fn untracked_diff_file(&self, path: &Path) -> DiffFile {
+        let full_path = self.location.join(path);
+
+        let (hunks, added_lines, is_binary) = match std::fs::read(&full_path) {
+            Ok(bytes) => match String::from_utf8(bytes) {
+                Ok(content) => {
+                    let lines: Vec<DiffLine> = content
+                        .lines()
+                        .map(|line| DiffLine {
+                            content: line.to_string(),
+                            line_type: DiffLineType::Added,
+                        })
+                        .collect();
+                    let added_lines = lines.len();
+                    let new_start = if added_lines == 0 { 0 } else { 1 };
+                    let hunk = DiffHunk {
+                        old_start: 0,
+                        old_lines: 0,
+                        new_start,
+                        new_lines: added_lines,
+                        header: format!("@@ -0,0 +{},{} @@", new_start, added_lines),
+                        lines,
+                    };
+                    (vec![hunk], added_lines, false)
+                }
+                Err(_) => (Vec::new(), 0, true),
+            },
+            Err(_) => (Vec::new(), 0, false),
+        };
+
+        DiffFile {
+            path: path.to_path_buf(),
+            old_path: None,
+            change_kind: ChangeKind::Added,
+            similarity: None,
+            hunks,
+            added_lines,
+            removed_lines: 0,
+            is_binary,
+            old_mode: None,
+            new_mode: None,
+        }
+    }
+}
+
+/// The `--format` string shared by `list_branches_info`/`list_branches_by_recency`.
+///
+/// Fields are delimited with `%x1f` (unit separator) rather than plain spaces so an empty
+/// `%(upstream:short)` atom (no upstream configured) doesn't shift the remaining fields, the
+/// way a whitespace split would.
+pub(crate) const BRANCH_LIST_FORMAT: &str =
+    "%(refname:short)%x1f%(objectname)%x1f%(HEAD)%x1f%(upstream:short)%x1f%(committerdate:unix)";
+
+/// Parses the `%x1f`-delimited lines produced by `BRANCH_LIST_FORMAT` into `Branch` structs.
+pub(crate) fn parse_branch_list_output(output: &str) -> Result<Vec<Branch>> {
+    let mut branches = Vec::new();
+
+    for line in output.lines() {
+        let parts: Vec<&str> = line.split('\x1f').collect();
+        if parts.len() >= 3 {
+            let name_str = parts[0];
+            let commit_str = parts[1];
+            let is_head = parts[2] == "*";
+
+            let upstream = parts.get(3).filter(|s| !s.is_empty()).map(|s| s.to_string());
+            let last_commit_timestamp = parts.get(4).and_then(|s| s.parse::<u64>().ok());
+
+            if let Ok(name) = BranchName::from_str(name_str) {
+                if let Ok(commit_hash) = CommitHash::from_str(commit_str) {
+                    branches.push(Branch {
+                        name,
+                        commit: commit_hash,
+                        is_head,
+                        upstream,
+                        last_commit_timestamp,
+                    });
+                } else {
+                    eprintln!("Warning: Could not parse commit hash '{}' for branch '{}'", commit_str, name_str);
+                }
+            } else {
+                eprintln!("Warning: Could not parse branch name '{}'", name_str);
+            }
+        }
+    }
+
+    Ok(branches)
+}
+
+/// Splits a `git stash list` subject (the `%gs` placeholder) into the branch the stash was
+/// created from, if named in the subject, and the remaining message.
+///
+/// Handles both the auto-generated `"WIP on <branch>: <message>"` form and the
+/// `"On <branch>: <message>"` form left by `git stash save "<message>"`.
+fn parse_stash_subject(subject: &str) -> (Option<String>, String) {
+    let rest = subject
+        .strip_prefix("WIP on ")
+        .or_else(|| subject.strip_prefix("On "));
+
+    match rest.and_then(|r| r.split_once(": ")) {
+        Some((branch, message)) => (Some(branch.to_string()), message.to_string()),
+        None => (None, subject.to_string()),
+    }
+}
+
+/// Parses the blank-line-separated blocks produced by `git worktree list --porcelain` into
+/// `Worktree` structs. The first block is always the main worktree.
+fn parse_worktree_list_output(output: &str) -> Result<Vec<Worktree>> {
+    let mut worktrees = Vec::new();
+
+    for (index, block) in output.split("\n\n").filter(|b| !b.trim().is_empty()).enumerate() {
+        let mut path: Option<PathBuf> = None;
+        let mut head: Option<CommitHash> = None;
+        let mut branch: Option<String> = None;
+        let mut is_bare = false;
+        let mut is_prunable = false;
+
+        for line in block.lines() {
+            if let Some(rest) = line.strip_prefix("worktree ") {
+                path = Some(PathBuf::from(rest));
+            } else if let Some(rest) = line.strip_prefix("HEAD ") {
+                head = CommitHash::from_str(rest).ok();
+            } else if let Some(rest) = line.strip_prefix("branch ") {
+                branch = Some(rest.trim_start_matches("refs/heads/").to_string());
+            } else if line == "bare" {
+                is_bare = true;
+            } else if line.starts_with("prunable") {
+                is_prunable = true;
+            }
+        }
+
+        let (path, head) = match (path, head) {
+            (Some(path), Some(head)) => (path, head),
+            _ => continue,
+        };
+
+        worktrees.push(Worktree {
+            path,
+            head,
+            branch,
+            is_main: index == 0,
+            is_bare,
+            is_prunable,
+        });
+    }
+
+    Ok(worktrees)
+}
+
+/// Parses the output of `git diff -z --unified=<n>` into a `DiffResult`.
+///
+/// The `-z` flag NUL-terminates the `---`/`+++` file path records, so paths
+/// containing spaces still parse unambiguously; everything else in the hunk
+/// body remains newline-delimited.
+fn parse_diff_output(output: &str) -> Result<DiffResult> {
+    let mut files = Vec::new();
+
+    for raw_file in output.split("diff --git ").filter(|s| !s.is_empty()) {
+        let mut old_path: Option<PathBuf> = None;
+        let mut new_path: Option<PathBuf> = None;
+        let mut change_kind = ChangeKind::Modified;
+        let mut similarity: Option<u8> = None;
+        let mut old_mode: Option<String> = None;
+        let mut new_mode: Option<String> = None;
+        let mut is_binary = false;
+        let mut hunks: Vec<DiffHunk> = Vec::new();
+        let mut added_lines = 0usize;
+        let mut removed_lines = 0usize;
+
+        let mut lines = raw_file.split('\n').peekable();
+        // First line is the "a/<path> b/<path>" header from `diff --git`.
+        lines.next();
+
+        let mut current_hunk: Option<DiffHunk> = None;
+
+        while let Some(line) = lines.next() {
+            if let Some(rest) = line.strip_prefix("rename from ") {
+                old_path = Some(PathBuf::from(rest.trim_end_matches('\0')));
+                change_kind = ChangeKind::Renamed;
+            } else if let Some(rest) = line.strip_prefix("rename to ") {
+                new_path = Some(PathBuf::from(rest.trim_end_matches('\0')));
+            } else if let Some(rest) = line.strip_prefix("copy from ") {
+                old_path = Some(PathBuf::from(rest.trim_end_matches('\0')));
+                change_kind = ChangeKind::Copied;
+            } else if let Some(rest) = line.strip_prefix("copy to ") {
+                new_path = Some(PathBuf::from(rest.trim_end_matches('\0')));
+            } else if let Some(rest) = line.strip_prefix("similarity index ") {
+                similarity = rest.trim_end_matches('%').parse::<u8>().ok();
+            } else if let Some(rest) = line.strip_prefix("old mode ") {
+                old_mode = Some(rest.to_string());
+            } else if let Some(rest) = line.strip_prefix("new mode ") {
+                new_mode = Some(rest.to_string());
+            } else if line.starts_with("new file mode") {
+                change_kind = ChangeKind::Added;
+            } else if line.starts_with("deleted file mode") {
+                change_kind = ChangeKind::Deleted;
+            } else if line.starts_with("Binary files") || line.starts_with("GIT binary patch") {
+                is_binary = true;
+            } else if let Some(rest) = line.strip_prefix("--- ") {
+                if rest != "/dev/null" {
+                    let path = rest.trim_start_matches("a/").trim_end_matches('\0');
+                    old_path.get_or_insert_with(|| PathBuf::from(path));
+                }
+            } else if let Some(rest) = line.strip_prefix("+++ ") {
+                if rest != "/dev/null" {
+                    let path = rest.trim_start_matches("b/").trim_end_matches('\0');
+                    new_path = Some(PathBuf::from(path));
+                }
+            } else if line.starts_with("@@ ") {
+                if let Some(hunk) = current_hunk.take() {
+                    hunks.push(hunk);
+                }
+                current_hunk = parse_hunk_header(line);
+            } else if let Some(hunk) = current_hunk.as_mut() {
+                if let Some(content) = line.strip_prefix('+') {
+                    added_lines += 1;
+                    hunk.lines.push(DiffLine {
+                        content: content.to_string(),
+                        line_type: DiffLineType::Added,
+                    });
+                } else if let Some(content) = line.strip_prefix('-') {
+                    removed_lines += 1;
+                    hunk.lines.push(DiffLine {
+                        content: content.to_string(),
+                        line_type: DiffLineType::Removed,
+                    });
+                } else if let Some(content) = line.strip_prefix(' ') {
+                    hunk.lines.push(DiffLine {
+                        content: content.to_string(),
+                        line_type: DiffLineType::Context,
+                    });
+                }
+            }
+        }
+
+        if let Some(hunk) = current_hunk.take() {
+            hunks.push(hunk);
+        }
+
+        let path = new_path.clone().or_else(|| old_path.clone());
+        let path = match path {
+            Some(p) => p,
+            None => continue,
+        };
+
+        if change_kind == ChangeKind::Modified && old_path.is_some() && new_path.is_some() && old_path != new_path {
+            change_kind = ChangeKind::Renamed;
+        }
+
+        files.push(DiffFile {
+            path,
+            old_path: if change_kind == ChangeKind::Renamed || change_kind == ChangeKind::Copied {
+                old_path
+            } else {
+                None
+            },
+            change_kind,
+            similarity,
+            hunks,
+            added_lines,
+            removed_lines,
+            is_binary,
+            old_mode,
+            new_mode,
+        });
+    }
+
+    Ok(DiffResult { files })
+}
+
+/// Parses a `@@ -old_start,old_lines +new_start,new_lines @@ [section heading]` hunk header.
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+    let body = line.strip_prefix("@@ ")?;
+    let end = body.find(" @@")?;
+    let ranges = &body[..end];
+
+    let mut parts = ranges.split_whitespace();
+    let old_range = parts.next()?.trim_start_matches('-');
+    let new_range = parts.next()?.trim_start_matches('+');
+
+    let (old_start, old_lines) = parse_range(old_range);
+    let (new_start, new_lines) = parse_range(new_range);
+
+    Some(DiffHunk {
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        header: line.to_string(),
+        lines: Vec::new(),
+    })
+}
+
+/// Parses a `start[,lines]` range as used in a unified diff hunk header.
+fn parse_range(range: &str) -> (usize, usize) {
+    let mut parts = range.splitn(2, ',');
+    let start = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let lines = parts.next().and_then(|s| s.parse().ok()).unwrap_or(1);
+    (start, lines)
+}
+
+/// Parses the tab-separated `<oid>\t<ref>` lines produced by `git ls-remote`.
+fn parse_ls_remote_output(output: &str) -> Result<Vec<RemoteRef>> {
+    let mut refs = Vec::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut parts = line.splitn(2, '\t');
+        let oid_str = match parts.next() {
+            Some(s) => s,
+            None => continue,
+        };
+        let ref_name = match parts.next() {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let oid = CommitHash::from_str(oid_str)?;
+
+        let (name, kind) = if ref_name == "HEAD" {
+            ("HEAD".to_string(), RemoteRefKind::Head)
+        } else if let Some(tag_name) = ref_name.strip_suffix("^{}") {
+            (
+                tag_name.trim_start_matches("refs/tags/").to_string(),
+                RemoteRefKind::Peeled,
+            )
+        } else if let Some(branch_name) = ref_name.strip_prefix("refs/heads/") {
+            (branch_name.to_string(), RemoteRefKind::Branch)
+        } else if let Some(tag_name) = ref_name.strip_prefix("refs/tags/") {
+            (tag_name.to_string(), RemoteRefKind::Tag)
+        } else {
+            (ref_name.to_string(), RemoteRefKind::Branch)
+        };
+
+        refs.push(RemoteRef { name, oid, kind });
+    }
+
+    Ok(refs)
+}
+
+/// Parses the ref-update report that `git fetch` writes to stderr, e.g.:
+///
+/// ```text
+///  * [new branch]      main       -> origin/main
+///    abc1234..def5678  develop    -> origin/develop
+///  + 1234567...abcdef1 feature    -> origin/feature  (forced update)
+///  x [deleted]         (none)     -> origin/stale
+/// ```
+///
+/// A `[new ...]` line carries no object ID, so a newly created ref is not added to
+/// `updated_refs`.
+fn parse_fetch_output(stderr: &str) -> FetchSummary {
+    let mut updated_refs = Vec::new();
+    let mut pruned = Vec::new();
+
+    for line in stderr.lines() {
+        let line = line.trim();
+        if line.len() < 2 {
+            continue;
+        }
+
+        let (flag, rest) = line.split_at(1);
+        let rest = rest.trim_start();
+        let forced = flag == "+";
+
+        let mut fields = rest.splitn(2, "->");
+        let range = match fields.next() {
+            Some(s) => s.trim(),
+            None => continue,
+        };
+        // Keep only the summary column, dropping the source ref name after it
+        // (e.g. "abc1234..def5678  develop" -> "abc1234..def5678").
+        let range = range.split_whitespace().next().unwrap_or(range);
+        let target = match fields.next() {
+            Some(s) => s.trim(),
+            None => continue,
+        };
+        // Strip any trailing annotation such as "  (forced update)".
+        let target = target.split_whitespace().next().unwrap_or(target);
+
+        if range.eq_ignore_ascii_case("[deleted]") {
+            pruned.push(target.to_string());
+            continue;
+        }
+
+        if let Some(new_tag) = range.strip_prefix("[new") {
+            let _ = new_tag;
+            if let Ok(new) = CommitHash::from_str(target) {
+                updated_refs.push(RefUpdate {
+                    name: target.to_string(),
+                    old: None,
+                    new,
+                    forced: false,
+                });
+            }
+            continue;
+        }
+
+        if let Some((old_str, new_str)) = range.split_once("..") {
+            let old_str = old_str.trim_matches('.');
+            let new_str = new_str.trim_matches('.');
+            if let (Ok(old), Ok(new)) = (CommitHash::from_str(old_str), CommitHash::from_str(new_str)) {
+                updated_refs.push(RefUpdate {
+                    name: target.to_string(),
+                    old: Some(old),
+                    new,
+                    forced,
+                });
+            }
+        }
+    }
+
+    FetchSummary { updated_refs, pruned }
+}
+
+/// Executes a Git command and returns its raw stdout bytes on success.
+///
+/// Unlike `execute_git_fn`, this does not assume UTF-8 output, which matters for reading
+/// arbitrary blob content via `cat-file`/`show`.
+fn execute_git_bytes<I, S, P>(p: P, args: I) -> Result<Vec<u8>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+    P: AsRef<Path>,
+{
+    let command_result = Command::new("git").current_dir(p.as_ref()).args(args).output();
+
+    match command_result {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(output.stdout)
+            } else {
+                let stdout = str::from_utf8(&output.stdout)
+                    .map(|s| s.trim_end().to_owned())
+                    .unwrap_or_else(|_| String::from("[stdout: undecodable UTF-8]"));
+                let stderr = str::from_utf8(&output.stderr)
+                    .map(|s| s.trim_end().to_owned())
+                    .unwrap_or_else(|_| String::from("[stderr: undecodable UTF-8]"));
+                Err(GitError::GitError { stdout, stderr })
+            }
+        }
+        Err(e) => {
+            if e.kind() == ErrorKind::NotFound {
+                Err(GitError::GitNotFound)
+            } else {
+                Err(GitError::Execution)
+            }
+        }
+    }
 }
\ No newline at end of file
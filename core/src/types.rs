@@ -1,11 +1,12 @@
 //! Defines core data types like URLs and Branch names for the Git library.
 use super::GitError;
-use once_cell::sync::Lazy; // Import Lazy
+use once_cell::sync::Lazy;
 use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{de, Deserialize, Deserializer};
 use std::hash::{Hash};
 use std::str::FromStr;
+use url::Url;
 use std::{
     ffi::OsStr, // Import OsStr
     fmt,
@@ -16,37 +17,285 @@ use std::{
 /// A specialized `Result` type for Git operations.
 pub type Result<A> = stdResult<A, GitError>;
 
-// Use Lazy to initialize the Regex safely and only once
-static GIT_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
-    // Regex from https://github.com/jonschlinkert/is-git-url - Compile time checked
-    Regex::new("(?:git|ssh|https?|git@[-\\w.]+):(//)?(.*?)(\\.git)(/?|\\#[-\\d\\w._]+?)$")
-        .expect("Invalid static Git URL regex") // Expect here is okay for static regex
-});
+/// The scheme (or scp-style pseudo-scheme) a `GitUrl` was addressed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GitUrlScheme {
+    Git,
+    Ssh,
+    Http,
+    Https,
+    File,
+    /// An scp-style remote with no `scheme://`, e.g. `git@github.com:user/repo.git`.
+    Scp,
+}
 
-/// Represents a validated Git URL.
+impl Display for GitUrlScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            GitUrlScheme::Git => "git",
+            GitUrlScheme::Ssh => "ssh",
+            GitUrlScheme::Http => "http",
+            GitUrlScheme::Https => "https",
+            GitUrlScheme::File => "file",
+            GitUrlScheme::Scp => "ssh", // scp-style addresses are an ssh shorthand
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Represents a validated, parsed Git URL.
 ///
-/// Can be created from a string using `FromStr`, which validates the format.
-#[derive(Debug, Clone)] // Added Clone
+/// Can be created from a string using `FromStr`, which validates the format and splits it
+/// into its components (`scheme`/`user`/`host`/`port`/`path`/`fragment`). `Display` round-trips
+/// the original input string unchanged; use `canonical()` to compare two different spellings
+/// of the same remote (e.g. an scp-style address against its `https://` equivalent).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GitUrl {
     pub(crate) value: String,
+    scheme: GitUrlScheme,
+    user: Option<String>,
+    host: Option<String>,
+    port: Option<u16>,
+    path: String,
+    fragment: Option<String>,
+}
+
+impl GitUrl {
+    /// The scheme this URL was addressed with (`scp` for an scp-style address with no
+    /// `scheme://` prefix).
+    pub fn scheme(&self) -> GitUrlScheme {
+        self.scheme
+    }
+
+    /// The user portion of the URL's authority, if present (e.g. `git` in
+    /// `git@github.com:user/repo.git`).
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// The host this URL points at, if present.
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// The explicit port this URL specifies, if any. Does not apply scp/ssh's implicit
+    /// default of 22; use `canonical()` to compare URLs with that default applied.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// The repository path, as given (not normalized).
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The `#fragment` (typically a ref name), if present.
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+
+    /// Returns a normalized `host/path` form for comparing two different spellings of the
+    /// same remote: the host is lowercased, a trailing `.git` and trailing slash are
+    /// stripped from the path, and an ssh/scp URL with no explicit port is treated as if
+    /// it specified the default port, 22.
+    ///
+    /// Two `GitUrl`s addressing the same repository (e.g. `git@github.com:user/repo.git`
+    /// and `ssh://git@github.com:22/user/repo`) produce equal `canonical()` strings.
+    pub fn canonical(&self) -> String {
+        let host = self.host.as_deref().unwrap_or("").to_lowercase();
+        let path = self
+            .path
+            .trim_start_matches('/')
+            .trim_end_matches('/')
+            .trim_end_matches(".git");
+
+        match self.canonical_port() {
+            Some(port) => format!("{}:{}/{}", host, port, path),
+            None => format!("{}/{}", host, path),
+        }
+    }
+
+    /// The port used for `canonical()`'s comparison: the explicit port if one was given,
+    /// otherwise ssh/scp's well-known default of 22.
+    fn canonical_port(&self) -> Option<u16> {
+        self.port.or(match self.scheme {
+            GitUrlScheme::Ssh | GitUrlScheme::Scp => Some(22),
+            _ => None,
+        })
+    }
+
+    /// Expands a shorthand repo reference like `gh:user/repo`, `gl:group/sub/repo`, or
+    /// `bb:user/repo` into the provider's canonical `https://` URL (with a `.git` suffix
+    /// appended for clone compatibility) and parses the result.
+    ///
+    /// The alias table mirrors `providers::shorthand_provider_type`, so a given prefix
+    /// always resolves to the same host on both sides.
+    ///
+    /// Returns `Err(GitError::InvalidUrl)` if `value` doesn't start with a known alias
+    /// prefix or has no path after it.
+    pub fn from_shorthand(value: &str) -> Result<GitUrl> {
+        let (prefix, path) = value
+            .split_once(':')
+            .ok_or_else(|| GitError::InvalidUrl(value.to_string()))?;
+
+        let host = SHORTHAND_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == prefix)
+            .map(|(_, host)| *host)
+            .ok_or_else(|| GitError::InvalidUrl(value.to_string()))?;
+
+        if path.is_empty() {
+            return Err(GitError::InvalidUrl(value.to_string()));
+        }
+
+        GitUrl::from_str(&format!("https://{}/{}.git", host, path))
+    }
 }
 
+/// Host aliases accepted by `GitUrl::from_shorthand`. Kept data-driven, and mirrored by
+/// `providers::shorthand_provider_type`, so adding a new alias only means adding a row
+/// here and the matching `ProviderType` arm there.
+const SHORTHAND_ALIASES: &[(&str, &str)] = &[
+    ("gh", "github.com"),
+    ("gl", "gitlab.com"),
+    ("bb", "bitbucket.org"),
+];
+
 impl FromStr for GitUrl {
     type Err = GitError;
 
-    /// Parses a string into a `GitUrl`, returning `Err(GitError::InvalidUrl)` if
-    /// the string does not match the expected Git URL pattern.
+    /// Parses a string into a `GitUrl`, returning `Err(GitError::InvalidUrl)` if the string
+    /// doesn't match a recognized `scheme://` form or an scp-style `[user@]host:path` form.
     fn from_str(value: &str) -> Result<Self> {
-        if GIT_URL_REGEX.is_match(value) {
-            Ok(GitUrl {
-                value: String::from(value),
-            })
+        if value.contains("://") {
+            parse_scheme_url(value)
         } else {
-            Err(GitError::InvalidUrl(value.to_string()))
+            parse_scp_url(value)
         }
     }
 }
 
+/// Maps a URL scheme string to a `GitUrlScheme`, rejecting schemes this library doesn't
+/// treat as a Git transport (e.g. `rsync`).
+fn known_scheme(scheme: &str) -> Option<GitUrlScheme> {
+    match scheme {
+        "git" => Some(GitUrlScheme::Git),
+        "ssh" => Some(GitUrlScheme::Ssh),
+        "http" => Some(GitUrlScheme::Http),
+        "https" => Some(GitUrlScheme::Https),
+        "file" => Some(GitUrlScheme::File),
+        _ => None,
+    }
+}
+
+/// Splits a trailing `#fragment` off of a path-like string, as used by both the
+/// `url::Url`-backed and scp-style parsers.
+fn split_fragment(s: &str) -> (&str, Option<String>) {
+    match s.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment.to_string())),
+        None => (s, None),
+    }
+}
+
+/// Parses a `scheme://...` URL, first trying `url::Url` for a precise authority split,
+/// falling back to a permissive manual split for inputs `url::Url` rejects (e.g. a
+/// non-numeric placeholder port like `ssh://host.xz:port/path`).
+fn parse_scheme_url(value: &str) -> Result<GitUrl> {
+    if let Ok(parsed) = Url::parse(value) {
+        let scheme = known_scheme(parsed.scheme()).ok_or_else(|| GitError::InvalidUrl(value.to_string()))?;
+        let user = {
+            let u = parsed.username();
+            if u.is_empty() { None } else { Some(u.to_string()) }
+        };
+
+        return Ok(GitUrl {
+            value: value.to_string(),
+            scheme,
+            user,
+            host: parsed.host_str().map(str::to_string),
+            port: parsed.port(),
+            path: parsed.path().to_string(),
+            fragment: parsed.fragment().map(str::to_string),
+        });
+    }
+
+    parse_scheme_url_lenient(value)
+}
+
+/// A permissive fallback for `scheme://` inputs `url::Url` rejects, reproducing the old
+/// regex-based parser's tolerance for malformed authorities (e.g. a non-numeric port).
+fn parse_scheme_url_lenient(value: &str) -> Result<GitUrl> {
+    let (scheme_str, rest) = value
+        .split_once("://")
+        .ok_or_else(|| GitError::InvalidUrl(value.to_string()))?;
+    let scheme = known_scheme(scheme_str).ok_or_else(|| GitError::InvalidUrl(value.to_string()))?;
+
+    let (authority, path_and_fragment) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], &rest[idx..]),
+        None => (rest, ""),
+    };
+    let (path, fragment) = split_fragment(path_and_fragment);
+
+    let (user, host_port) = match authority.split_once('@') {
+        Some((u, h)) => (Some(u.to_string()), h),
+        None => (None, authority),
+    };
+
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((h, p)) => (h.to_string(), p.parse().ok()),
+        None => (host_port.to_string(), None),
+    };
+
+    if host.is_empty() {
+        return Err(GitError::InvalidUrl(value.to_string()));
+    }
+
+    Ok(GitUrl {
+        value: value.to_string(),
+        scheme,
+        user,
+        host: Some(host),
+        port,
+        path: path.to_string(),
+        fragment,
+    })
+}
+
+/// Parses an scp-style `[user@]host:path` address, e.g. `git@github.com:user/repo.git`.
+/// The colon must precede a `/`-containing path with no leading slash, distinguishing this
+/// form from an absolute local path (`host.xz:/path` stays invalid, matching real Git's
+/// own handling of a leading slash as "absolute path on the remote").
+fn parse_scp_url(value: &str) -> Result<GitUrl> {
+    let colon_idx = value.find(':').ok_or_else(|| GitError::InvalidUrl(value.to_string()))?;
+    let left = &value[..colon_idx];
+    let right = &value[colon_idx + 1..];
+
+    if left.is_empty() || right.starts_with('/') || !right.contains('/') {
+        return Err(GitError::InvalidUrl(value.to_string()));
+    }
+
+    let (user, host) = match left.split_once('@') {
+        Some((u, h)) => (Some(u.to_string()), h.to_string()),
+        None => (None, left.to_string()),
+    };
+
+    if host.is_empty() {
+        return Err(GitError::InvalidUrl(value.to_string()));
+    }
+
+    let (path, fragment) = split_fragment(right);
+
+    Ok(GitUrl {
+        value: value.to_string(),
+        scheme: GitUrlScheme::Scp,
+        user,
+        host: Some(host),
+        port: None,
+        path: path.to_string(),
+        fragment,
+    })
+}
+
 impl Display for GitUrl {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.value)
@@ -192,15 +441,54 @@ impl AsRef<OsStr> for CommitHash {
 
 // --- Remote Type ---
 
+/// A Git remote: either a configured symbolic name (`origin`) or a URL used directly in
+/// its place, since `git fetch`/`git push`/etc. accept either wherever a remote is
+/// expected.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Remote {
-    value: String,
+pub enum Remote {
+    /// A symbolic remote name, e.g. `origin`.
+    Symbol(String),
+    /// A URL used directly in place of a configured remote.
+    Url(GitUrl),
+}
+
+impl Remote {
+    /// Returns the symbolic name, if this is `Remote::Symbol`.
+    pub fn as_symbol(&self) -> Option<&str> {
+        match self {
+            Remote::Symbol(name) => Some(name),
+            Remote::Url(_) => None,
+        }
+    }
+
+    /// Returns the URL, if this is `Remote::Url`.
+    pub fn as_url(&self) -> Option<&GitUrl> {
+        match self {
+            Remote::Symbol(_) => None,
+            Remote::Url(url) => Some(url),
+        }
+    }
+
+    /// Builds a `Remote::Symbol` from a name read from existing Git configuration,
+    /// skipping the `check-ref-format`-style validation `FromStr` applies. Config may
+    /// already contain a remote name that wouldn't pass that validation today; this lets
+    /// it round-trip instead of being silently dropped.
+    pub fn from_config_unchecked(s: &str) -> Remote {
+        Remote::Symbol(s.to_string())
+    }
 }
 
 impl FromStr for Remote {
     type Err = GitError;
 
+    /// Parses `s` as a `Remote`, preferring the URL interpretation: if `s` parses as a
+    /// `GitUrl`, this returns `Remote::Url`. Otherwise `s` is validated as a symbolic
+    /// remote name (non-empty, no whitespace or control characters).
     fn from_str(s: &str) -> Result<Self> {
+        if let Ok(url) = GitUrl::from_str(s) {
+            return Ok(Remote::Url(url));
+        }
+
         // Basic validation: non-empty, no whitespace, no control characters.
         // Git might allow more, but this covers common safe cases.
         if !s.is_empty()
@@ -208,9 +496,7 @@ impl FromStr for Remote {
             .chars()
             .any(|c| c.is_ascii_whitespace() || c.is_ascii_control())
         {
-            Ok(Remote {
-                value: s.to_string(),
-            })
+            Ok(Remote::Symbol(s.to_string()))
         } else {
             Err(GitError::InvalidRemoteName(s.to_string()))
         }
@@ -219,19 +505,26 @@ impl FromStr for Remote {
 
 impl Display for Remote {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.value)
+        match self {
+            Remote::Symbol(name) => write!(f, "{}", name),
+            Remote::Url(url) => write!(f, "{}", url),
+        }
     }
 }
 
 impl AsRef<str> for Remote {
     fn as_ref(&self) -> &str {
-        &self.value
+        match self {
+            Remote::Symbol(name) => name,
+            Remote::Url(url) => url.as_ref(),
+        }
     }
 }
 
 impl AsRef<OsStr> for Remote {
     fn as_ref(&self) -> &OsStr {
-        self.value.as_ref()
+        let s: &str = self.as_ref();
+        s.as_ref()
     }
 }
 
@@ -361,6 +654,15 @@ mod tests {
             "ssh://user@host.xz/~/path/to/repo.git",
             "ssh://user@host.xz/~user/path/to/repo.git/",
             "ssh://user@host.xz:port/path/to/repo.git/",
+            // scp-style addresses: `[user@]host:path`, no `scheme://` prefix required.
+            "file:///path/to/repo.git/",
+            "file://~/path/to/repo.git/",
+            "git@github.com:user/some_project.git/foo",
+            "git@github.com:user/some_project.gitfoo",
+            "host.xz:path/to/repo.git",
+            "host.xz:~user/path/to/repo.git/",
+            "user@host.xz:path/to/repo.git",
+            "user@host.xz:~user/path/to/repo.git/",
         ];
 
         for url in valid_urls.iter() {
@@ -372,18 +674,10 @@ mod tests {
     fn test_invalid_git_urls() {
         let invalid_urls = vec![
             "/path/to/repo.git/",
-            "file:///path/to/repo.git/",
-            "file://~/path/to/repo.git/",
-            "git@github.com:user/some_project.git/foo",
-            "git@github.com:user/some_project.gitfoo",
-            "host.xz:/path/to/repo.git/",
-            "host.xz:path/to/repo.git", // Often works with git CLI, but doesn't fit the strict regex
-            "host.xz:~user/path/to/repo.git/",
+            "host.xz:/path/to/repo.git/", // leading slash after the colon is an absolute remote path, not an scp path
             "path/to/repo.git/",
-            "rsync://host.xz/path/to/repo.git/",
-            "user@host.xz:/path/to/repo.git/", // Same as host.xz:path...
-            "user@host.xz:path/to/repo.git",
-            "user@host.xz:~user/path/to/repo.git/",
+            "rsync://host.xz/path/to/repo.git/", // rsync is not a recognized Git transport
+            "user@host.xz:/path/to/repo.git/",   // same as host.xz:/path...
             "~/path/to/repo.git",
         ];
 
@@ -392,6 +686,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_git_url_components() {
+        let https = GitUrl::from_str("https://github.com/user/repo.git").unwrap();
+        assert_eq!(https.scheme(), GitUrlScheme::Https);
+        assert_eq!(https.user(), None);
+        assert_eq!(https.host(), Some("github.com"));
+        assert_eq!(https.path(), "/user/repo.git");
+
+        let scp = GitUrl::from_str("git@github.com:user/repo.git").unwrap();
+        assert_eq!(scp.scheme(), GitUrlScheme::Scp);
+        assert_eq!(scp.user(), Some("git"));
+        assert_eq!(scp.host(), Some("github.com"));
+        assert_eq!(scp.path(), "user/repo.git");
+
+        let fragment = GitUrl::from_str("git://github.com/ember-cli/ember-cli.git#v0.1.0").unwrap();
+        assert_eq!(fragment.fragment(), Some("v0.1.0"));
+
+        let ssh = GitUrl::from_str("ssh://git@host.xz:2222/path/to/repo.git").unwrap();
+        assert_eq!(ssh.port(), Some(2222));
+    }
+
+    #[test]
+    fn test_git_url_from_shorthand() {
+        let gh = GitUrl::from_shorthand("gh:user/repo").unwrap();
+        assert_eq!(gh.to_string(), "https://github.com/user/repo.git");
+
+        let gl = GitUrl::from_shorthand("gl:group/sub/repo").unwrap();
+        assert_eq!(gl.to_string(), "https://gitlab.com/group/sub/repo.git");
+
+        let bb = GitUrl::from_shorthand("bb:user/repo").unwrap();
+        assert_eq!(bb.to_string(), "https://bitbucket.org/user/repo.git");
+
+        assert!(GitUrl::from_shorthand("hub:user/repo").is_err());
+        assert!(GitUrl::from_shorthand("gh:").is_err());
+    }
+
+    #[test]
+    fn test_git_url_canonical_equality() {
+        let scp = GitUrl::from_str("git@GitHub.com:user/repo.git").unwrap();
+        let ssh = GitUrl::from_str("ssh://git@github.com:22/user/repo").unwrap();
+        assert_eq!(scp.canonical(), ssh.canonical());
+
+        let other = GitUrl::from_str("git@github.com:user/other.git").unwrap();
+        assert_ne!(scp.canonical(), other.canonical());
+    }
+
     #[test]
     fn test_valid_reference_names() {
         let valid_references = vec![
@@ -492,6 +832,23 @@ fn test_invalid_remote_name() {
     assert!(Remote::from_str("my\nremote").is_err()); // Control char
 }
 
+#[test]
+fn test_remote_url_variant() {
+    let remote = Remote::from_str("https://github.com/user/repo.git").unwrap();
+    assert!(matches!(remote, Remote::Url(_)));
+    assert!(remote.as_url().is_some());
+    assert_eq!(remote.as_symbol(), None);
+    assert_eq!(remote.to_string(), "https://github.com/user/repo.git");
+}
+
+#[test]
+fn test_remote_from_config_unchecked() {
+    // A name that `FromStr` would reject (a space), but that may already exist in a
+    // user's config and must still round-trip.
+    let remote = Remote::from_config_unchecked("my remote");
+    assert_eq!(remote.as_symbol(), Some("my remote"));
+}
+
 #[test]
 fn test_valid_tag_name() {
     // Reuses branch name validation logic implicitly
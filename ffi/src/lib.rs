@@ -1,6 +1,6 @@
 use gitlens_core::analytics;
 use gitlens_core::models;
-use gitlens_core::repository::Repository;
+use gitlens_core::repository::{DiffOptions, Repository};
 use gitlens_core::types::{BranchName, GitUrl, Result};
 
 use pyo3::create_exception;
@@ -82,10 +82,38 @@ impl PyRepository {
         Ok(PyRepository { inner: result })
     }
 
-    /// List all branches in the repository.
-    fn list_branches(&self) -> PyResult<Vec<String>> {
-        let branches = self.inner.list_branches().map_err(to_py_err)?;
-        Ok(branches.iter().map(|b| b.to_string()).collect())
+    /// List all branches in the repository, with commit, HEAD, upstream, and recency info.
+    fn list_branches<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyDict>> {
+        let branches = self.inner.list_branches_info().map_err(to_py_err)?;
+
+        let result = branches.iter().map(|b| {
+            let branch_dict = PyDict::new(py);
+            branch_dict.set_item("name", b.name.to_string()).unwrap();
+            branch_dict.set_item("commit", b.commit.to_string()).unwrap();
+            branch_dict.set_item("is_head", b.is_head).unwrap();
+            branch_dict.set_item("upstream", &b.upstream).unwrap();
+            branch_dict.set_item("last_commit_timestamp", b.last_commit_timestamp).unwrap();
+            branch_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// List all branches, sorted by descending last-commit recency.
+    fn list_branches_by_recency<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyDict>> {
+        let branches = self.inner.list_branches_by_recency().map_err(to_py_err)?;
+
+        let result = branches.iter().map(|b| {
+            let branch_dict = PyDict::new(py);
+            branch_dict.set_item("name", b.name.to_string()).unwrap();
+            branch_dict.set_item("commit", b.commit.to_string()).unwrap();
+            branch_dict.set_item("is_head", b.is_head).unwrap();
+            branch_dict.set_item("upstream", &b.upstream).unwrap();
+            branch_dict.set_item("last_commit_timestamp", b.last_commit_timestamp).unwrap();
+            branch_dict
+        }).collect();
+
+        Ok(result)
     }
 
     /// List all tracked files in the repository.
@@ -257,9 +285,296 @@ impl PyRepository {
         result.set_item("ahead_count", divergence.ahead_count)?;
         result.set_item("behind_count", divergence.behind_count)?;
         result.set_item("differing_files", divergence.differing_files)?;
+        let conflicting_files: Vec<String> = divergence
+            .conflicting_files
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        result.set_item("conflicting_files", conflicting_files)?;
+        result.set_item("can_fast_forward", divergence.can_fast_forward)?;
+
+        Ok(result)
+    }
+
+    /// Export a commit range as a mailbox-style patch series, one dict per commit.
+    fn export_patch_series<'py>(&self, py: Python<'py>, range: &str) -> PyResult<Vec<&'py PyDict>> {
+        let patches = self.inner.export_patch_series(range).map_err(to_py_err)?;
+
+        let result = patches.iter().map(|patch| {
+            let patch_dict = PyDict::new(py);
+            patch_dict.set_item("hash", patch.hash.to_string()).unwrap();
+            patch_dict.set_item("author_name", &patch.author_name).unwrap();
+            patch_dict.set_item("author_email", &patch.author_email).unwrap();
+            patch_dict.set_item("timestamp", patch.timestamp).unwrap();
+            patch_dict.set_item("message", &patch.message).unwrap();
+            patch_dict.set_item("diff_text", &patch.diff_text).unwrap();
+            patch_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// Apply (or dry-run check) a patch series produced by `export_patch_series`.
+    ///
+    /// `patches` is a list of dicts, each requiring at least the `diff_text` key.
+    fn apply_patch_series<'py>(
+        &self,
+        py: Python<'py>,
+        patches: Vec<&PyDict>,
+        dry_run: bool,
+    ) -> PyResult<&'py PyDict> {
+        let patches: Vec<gitlens_core::patch::PatchEntry> = patches
+            .into_iter()
+            .map(|p| {
+                let hash_str: String = match p.get_item("hash") {
+                    Ok(Some(v)) => v.extract()?,
+                    _ => "0000".to_string(),
+                };
+                let author_name: String = match p.get_item("author_name") {
+                    Ok(Some(v)) => v.extract()?,
+                    _ => String::new(),
+                };
+                let author_email: String = match p.get_item("author_email") {
+                    Ok(Some(v)) => v.extract()?,
+                    _ => String::new(),
+                };
+                let timestamp: u64 = match p.get_item("timestamp") {
+                    Ok(Some(v)) => v.extract()?,
+                    _ => 0,
+                };
+                let message: String = match p.get_item("message") {
+                    Ok(Some(v)) => v.extract()?,
+                    _ => String::new(),
+                };
+                let diff_text: String = match p.get_item("diff_text") {
+                    Ok(Some(v)) => v.extract()?,
+                    _ => return Err(pyo3::exceptions::PyKeyError::new_err("diff_text")),
+                };
+
+                let hash = gitlens_core::types::CommitHash::from_str(&hash_str).map_err(to_py_err)?;
+
+                Ok(gitlens_core::patch::PatchEntry {
+                    hash,
+                    author_name,
+                    author_email,
+                    timestamp,
+                    message,
+                    diff_text,
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let apply_result = self.inner.apply_patch_series(&patches, dry_run).map_err(to_py_err)?;
+
+        let result = PyDict::new(py);
+        result.set_item("success", apply_result.success)?;
+        result.set_item("failed_hunks", apply_result.failed_hunks)?;
 
         Ok(result)
     }
+
+    /// Compute a structured diff between two revisions.
+    fn diff<'py>(
+        &self,
+        py: Python<'py>,
+        from: &str,
+        to: &str,
+        context_lines: Option<u32>,
+    ) -> PyResult<&'py PyDict> {
+        let mut opts = DiffOptions::default();
+        if let Some(lines) = context_lines {
+            opts.context_lines = lines;
+        }
+
+        let diff_result = self.inner.diff(from, to, opts).map_err(to_py_err)?;
+
+        let result = PyDict::new(py);
+        let files = PyList::new(py, diff_result.files.iter().map(|f| diff_file_to_dict(py, f)));
+        result.set_item("files", files)?;
+
+        Ok(result)
+    }
+
+    /// Get line-by-line blame information for a file, optionally as of a given revision.
+    fn blame<'py>(&self, py: Python<'py>, path: &str, rev: Option<&str>) -> PyResult<Vec<&'py PyDict>> {
+        let blame_lines = self.inner.blame(path, rev).map_err(to_py_err)?;
+
+        let result = blame_lines.iter().map(|line| {
+            let line_dict = PyDict::new(py);
+            line_dict.set_item("hash", line.hash.to_string()).unwrap();
+            line_dict.set_item("author", &line.author).unwrap();
+            line_dict.set_item("original_line", line.original_line).unwrap();
+            line_dict.set_item("final_line", line.final_line).unwrap();
+            line_dict.set_item("timestamp", line.timestamp).unwrap();
+            line_dict.set_item("content", &line.content).unwrap();
+            line_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// List the repository's stash entries.
+    fn list_stashes<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyDict>> {
+        let stashes = self.inner.list_stashes().map_err(to_py_err)?;
+
+        let result = stashes.iter().map(|s| {
+            let stash_dict = PyDict::new(py);
+            stash_dict.set_item("reference", s.reference.to_string()).unwrap();
+            stash_dict.set_item("branch", &s.branch).unwrap();
+            stash_dict.set_item("message", &s.message).unwrap();
+            stash_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// List the repository's worktrees.
+    fn list_worktrees<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyDict>> {
+        let worktrees = self.inner.list_worktrees().map_err(to_py_err)?;
+
+        let result = worktrees.iter().map(|w| {
+            let worktree_dict = PyDict::new(py);
+            worktree_dict.set_item("path", w.path.to_string_lossy().to_string()).unwrap();
+            worktree_dict.set_item("head", w.head.to_string()).unwrap();
+            worktree_dict.set_item("branch", &w.branch).unwrap();
+            worktree_dict.set_item("is_main", w.is_main).unwrap();
+            worktree_dict.set_item("is_bare", w.is_bare).unwrap();
+            worktree_dict.set_item("is_prunable", w.is_prunable).unwrap();
+            worktree_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// List tags in the repository.
+    fn list_tags<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyDict>> {
+        let tags = self.inner.list_tags().map_err(to_py_err)?;
+
+        let result = tags.iter().map(|t| {
+            let tag_dict = PyDict::new(py);
+            tag_dict.set_item("name", t.name.to_string()).unwrap();
+            tag_dict.set_item("target", t.target.to_string()).unwrap();
+            tag_dict.set_item("annotated", t.annotated).unwrap();
+            tag_dict.set_item("message", &t.message).unwrap();
+            tag_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// List all references (local branches, remote-tracking branches, tags, notes).
+    fn list_references<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyDict>> {
+        let refs = self.inner.list_references().map_err(to_py_err)?;
+
+        let result = refs.iter().map(|r| {
+            let ref_dict = PyDict::new(py);
+            ref_dict.set_item("name", &r.name).unwrap();
+            ref_dict.set_item("ref_type", format!("{:?}", r.ref_type)).unwrap();
+            ref_dict.set_item("target", r.target.to_string()).unwrap();
+            ref_dict
+        }).collect();
+
+        Ok(result)
+    }
+
+    /// Analyze contribution and ownership statistics per monorepo subproject.
+    ///
+    /// `projects` is a list of `(id, paths)` pairs, where `paths` are path roots relative to
+    /// the repository root that belong to that project.
+    fn analyze_contributions_by_project<'py>(
+        &self,
+        py: Python<'py>,
+        projects: Vec<(String, Vec<String>)>,
+    ) -> PyResult<&'py PyDict> {
+        let projects: Vec<analytics::ProjectDefinition> = projects
+            .into_iter()
+            .map(|(id, paths)| analytics::ProjectDefinition { id, paths })
+            .collect();
+
+        let stats_by_project = self.inner.analyze_contributions_by_project(&projects).map_err(to_py_err)?;
+
+        let result = PyDict::new(py);
+        for (project_id, stats) in stats_by_project {
+            let contributions = PyDict::new(py);
+            contributions.set_item("total_commits", stats.contributions.total_commits)?;
+            contributions.set_item("total_authors", stats.contributions.total_authors)?;
+            contributions.set_item("total_added", stats.contributions.total_added)?;
+            contributions.set_item("total_removed", stats.contributions.total_removed)?;
+            contributions.set_item("total_files_changed", stats.contributions.total_files_changed)?;
+
+            let authors = PyDict::new(py);
+            for (author, author_stats) in stats.contributions.by_author {
+                let author_dict = PyDict::new(py);
+                author_dict.set_item("commits", author_stats.commits)?;
+                author_dict.set_item("added_lines", author_stats.added_lines)?;
+                author_dict.set_item("removed_lines", author_stats.removed_lines)?;
+                author_dict.set_item("files_changed", author_stats.files_changed)?;
+                author_dict.set_item("first_commit", author_stats.first_commit)?;
+                author_dict.set_item("last_commit", author_stats.last_commit)?;
+
+                authors.set_item(author, author_dict)?;
+            }
+            contributions.set_item("by_author", authors)?;
+
+            let file_owners = PyDict::new(py);
+            for (path, owners) in stats.ownership.files {
+                file_owners.set_item(path.to_string_lossy().to_string(), owners)?;
+            }
+
+            let dir_owners = PyDict::new(py);
+            for (dir, owners) in stats.ownership.directories {
+                dir_owners.set_item(dir.to_string_lossy().to_string(), owners)?;
+            }
+
+            let ownership = PyDict::new(py);
+            ownership.set_item("files", file_owners)?;
+            ownership.set_item("directories", dir_owners)?;
+
+            let project_dict = PyDict::new(py);
+            project_dict.set_item("contributions", contributions)?;
+            project_dict.set_item("ownership", ownership)?;
+
+            result.set_item(project_id, project_dict)?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Converts a `DiffFile` into the `PyDict` shape shared by the `diff` binding.
+fn diff_file_to_dict<'py>(py: Python<'py>, file: &models::DiffFile) -> &'py PyDict {
+    let file_dict = PyDict::new(py);
+    file_dict.set_item("path", file.path.to_string_lossy().to_string()).unwrap();
+    file_dict.set_item("old_path", file.old_path.as_ref().map(|p| p.to_string_lossy().to_string())).unwrap();
+    file_dict.set_item("change_kind", format!("{:?}", file.change_kind)).unwrap();
+    file_dict.set_item("similarity", file.similarity).unwrap();
+    file_dict.set_item("added_lines", file.added_lines).unwrap();
+    file_dict.set_item("removed_lines", file.removed_lines).unwrap();
+    file_dict.set_item("is_binary", file.is_binary).unwrap();
+    file_dict.set_item("old_mode", &file.old_mode).unwrap();
+    file_dict.set_item("new_mode", &file.new_mode).unwrap();
+
+    let hunks = PyList::new(py, file.hunks.iter().map(|h| {
+        let hunk_dict = PyDict::new(py);
+        hunk_dict.set_item("old_start", h.old_start).unwrap();
+        hunk_dict.set_item("old_lines", h.old_lines).unwrap();
+        hunk_dict.set_item("new_start", h.new_start).unwrap();
+        hunk_dict.set_item("new_lines", h.new_lines).unwrap();
+        hunk_dict.set_item("header", &h.header).unwrap();
+
+        let lines = PyList::new(py, h.lines.iter().map(|l| {
+            let line_dict = PyDict::new(py);
+            line_dict.set_item("content", &l.content).unwrap();
+            line_dict.set_item("line_type", format!("{:?}", l.line_type)).unwrap();
+            line_dict
+        }));
+        hunk_dict.set_item("lines", lines).unwrap();
+
+        hunk_dict
+    }));
+    file_dict.set_item("hunks", hunks).unwrap();
+
+    file_dict
 }
 
 /// Python module configuration